@@ -1,6 +1,7 @@
 use avian2d::prelude::*;
 use avian2d::PhysicsPlugins;
 use bevy::prelude::*;
+use physics_layers::world_collision_layers;
 use simple_platform_player_controller::PlayerPlugin;
 
 pub struct SimplePlatformGame;
@@ -75,6 +76,7 @@ fn start_simple_platform_game(
     Transform,
     Collider(|| Collider::rectangle(16., 16.)),
     RigidBody(|| RigidBody::Static),
+    CollisionLayers(world_collision_layers),
     Friction(|| Friction::new(1.))
 )]
 struct Tile;
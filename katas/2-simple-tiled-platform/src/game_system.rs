@@ -1,10 +1,26 @@
 use avian2d::prelude::*;
 use avian2d::PhysicsPlugins;
 use bevy::prelude::*;
+use bevy::utils::HashMap;
 use bevy_ecs_tiled::prelude::*;
+use bevy_ecs_tiled::TiledMapPluginConfig;
 use bevy_ecs_tilemap::prelude::*;
+use breakables::tiled_map::is_breakable_tile_collider;
+use breakables::{BreakableHit, BreakablePlugin};
+use collectibles::{CollectibleKind, CollectiblePlugin, CollectibleSprites, Collected};
+use level_mechanics::tiled_map::is_mechanic_collider;
+use level_mechanics::{DoorChanged, LevelMechanicsPlugin, SwitchToggled};
+use physics_layers::{sensor_collision_layers, world_collision_layers};
+use simple_2d_camera::PixelCamera;
 use simple_platform_player_controller::player_components::PlayerSpawn;
 use simple_platform_player_controller::{Player, PlayerPlugin, PlayerSpawnSettings};
+use triggers::tiled_map::is_trigger_collider;
+use triggers::{TriggerEvent, TriggerPlugin};
+use crate::tile_colliders::{is_merged_tile_collider, merged_tile_colliders_observer};
+use crate::tiled_world::{is_map_door_collider, TiledWorldHandle, TiledWorldPlugin};
+
+/// Damage dealt to a breakable tile when the player bumps it from below.
+const HEAD_BUMP_DAMAGE: i32 = 1;
 
 pub struct SimplePlatformGame;
 
@@ -13,12 +29,20 @@ impl Plugin for SimplePlatformGame {
         app.add_plugins((
             PhysicsPlugins::default().with_length_unit(16.),
             PlayerPlugin,
+            CollectiblePlugin,
             TilemapPlugin,
-            TiledMapPlugin::default(),
+            // Tiled types are exported on demand, see `tiled_types`.
+            TiledMapPlugin(TiledMapPluginConfig {
+                tiled_types_export_file: None,
+            }),
             TiledPhysicsPlugin::<StaticTiledAvianBackend>::default(),
+            TiledWorldPlugin,
+            TriggerPlugin,
+            LevelMechanicsPlugin,
+            BreakablePlugin,
         ));
 
-        app.register_type::<Collectible>();
+        app.add_observer(merged_tile_colliders_observer);
         app.register_type::<PlayerSpawn>();
         #[cfg(feature = "avian-debug")]
         app.add_plugins(PhysicsDebugPlugin::default());
@@ -26,27 +50,23 @@ impl Plugin for SimplePlatformGame {
         app.add_systems(
             Update,
             (
-                collectible_system,
-                player_collectible_collider_system,
+                collected_system,
+                trigger_system,
+                mechanics_system,
                 player_spawn_system,
+                head_bump_system,
             ),
         );
         app.insert_resource(Gravity(Vec2::new(0., -9.81 * 32.)));
     }
 }
 
-#[derive(Resource)]
-pub struct GameSpriteSheet {
-    pub coin: Handle<Image>,
-}
-
 fn start_simple_platform_game(mut commands: Commands, asset_server: Res<AssetServer>) {
     let coin_handle: Handle<Image> = asset_server.load("katas/2/sprites/coin.png");
 
-    commands.insert_resource(GameSpriteSheet { coin: coin_handle });
+    commands.insert_resource(CollectibleSprites(HashMap::from([(CollectibleKind::Coin, coin_handle)])));
 
-    let map_handle: Handle<TiledMap> = asset_server.load("katas/2/map.rendered.tmx");
-    commands.spawn(TiledMapHandle(map_handle));
+    commands.spawn(TiledWorldHandle(asset_server.load("katas/2/world.world")));
 }
 
 #[derive(Default)]
@@ -59,26 +79,57 @@ impl TiledPhysicsBackend for StaticTiledAvianBackend {
         map: &tiled::Map,
         collider_source: &TiledColliderSource,
     ) -> Option<TiledColliderSpawnInfos> {
+        // Triggers, switches, doors and breakable tiles spawn their own colliders.
+        if is_merged_tile_collider(map, collider_source)
+            || is_trigger_collider(map, collider_source)
+            || is_mechanic_collider(map, collider_source)
+            || is_breakable_tile_collider(map, collider_source)
+        {
+            return None;
+        }
+
+        if is_map_door_collider(map, collider_source) {
+            return self.0.spawn_collider(commands, map, collider_source).inspect(|collider| {
+                commands.entity(collider.entity).insert((Sensor, sensor_collision_layers()));
+            });
+        }
+
         self.0
             .spawn_collider(commands, map, collider_source)
             .map(|collider| {
                 commands.entity(collider.entity).insert(RigidBody::Static);
+                commands.entity(collider.entity).insert(world_collision_layers());
                 commands.entity(collider.entity).insert(Friction::new(0.));
                 collider
             })
     }
 }
 
+type PlayerSpawnQuery<'w, 's> = Query<
+    'w,
+    's,
+    &'static Transform,
+    (Added<PlayerSpawn>, Without<Player>, Without<PixelCamera>),
+>;
+
+/// Places the player on the first spawn only, world maps streaming back in spawn theirs again.
 fn player_spawn_system(
+    mut spawned: Local<bool>,
     mut spawn_settings: ResMut<PlayerSpawnSettings>,
     mut player: Query<&mut Transform, With<Player>>,
-    mut camera: Query<&mut Transform, (With<Camera>, Without<Player>)>,
-    spawn_entity: Query<&Transform, (Added<PlayerSpawn>, Without<Player>, Without<Camera>)>,
+    mut camera: Query<&mut Transform, (With<PixelCamera>, Without<Player>)>,
+    spawn_entity: PlayerSpawnQuery,
 ) {
+    if *spawned {
+        return;
+    }
+
     let Ok(spawn_transform) = spawn_entity.get_single() else {
         return;
     };
 
+    *spawned = true;
+
     info!("Spawning player at {:?}", spawn_transform.translation);
     spawn_settings.position.x = spawn_transform.translation.x;
     spawn_settings.position.y = spawn_transform.translation.y;
@@ -94,43 +145,57 @@ fn player_spawn_system(
     }
 }
 
-#[derive(Component, Reflect, Default)]
-#[reflect(Component, Default)]
-struct Collectible {}
-
-fn collectible_system(
-    mut commands: Commands,
-    sprites: Res<GameSpriteSheet>,
-    collectibles: Query<Entity, Added<Collectible>>,
+/// Hits whatever the player's head runs into, breaking breakable tiles since the player has no attack.
+fn head_bump_system(
+    mut collision_events: EventReader<CollisionStarted>,
+    mut breakable_hits: EventWriter<BreakableHit>,
+    players: Query<&GlobalTransform, With<Player>>,
+    transforms: Query<&GlobalTransform>,
 ) {
-    for entity in collectibles.iter() {
-        commands.entity(entity).insert((
-            Sprite {
-                image: sprites.coin.clone(),
-                ..default()
-            },
-            Collider::circle(4.),
-            CollisionLayers::new(0b00100, 0b00101),
-            CollidingEntities::default(),
-        ));
+    for CollisionStarted(a, b) in collision_events.read() {
+        let (player_transform, other) = match (players.get(*a), players.get(*b)) {
+            (Ok(player_transform), _) => (player_transform, *b),
+            (_, Ok(player_transform)) => (player_transform, *a),
+            _ => continue,
+        };
+
+        let Ok(other_transform) = transforms.get(other) else {
+            continue;
+        };
+
+        // Only things above the player, not the ground or walls it touches.
+        let offset = (other_transform.translation() - player_transform.translation()).truncate();
+
+        if offset.y > offset.x.abs() {
+            breakable_hits.send(BreakableHit {
+                target: other,
+                damage: HEAD_BUMP_DAMAGE,
+            });
+        }
+    }
+}
+
+fn collected_system(mut collected_events: EventReader<Collected>) {
+    for collected in collected_events.read() {
+        info!("Player collected {:?} x{}", collected.kind, collected.value);
+    }
+}
+
+fn trigger_system(mut trigger_events: EventReader<TriggerEvent>) {
+    for trigger in trigger_events.read() {
+        info!("Player triggered {} ({:?})", trigger.id, trigger.kind);
     }
 }
 
-fn player_collectible_collider_system(
-    mut commands: Commands,
-    colliders: Query<(Entity, &CollidingEntities)>,
-    player: Query<Entity, With<Player>>,
+fn mechanics_system(
+    mut toggled_events: EventReader<SwitchToggled>,
+    mut door_events: EventReader<DoorChanged>,
 ) {
-    let Some(player_entity) = player.iter().next() else {
-        return;
-    };
+    for toggled in toggled_events.read() {
+        info!("Switch {} turned {}", toggled.switch, if toggled.on { "on" } else { "off" });
+    }
 
-    for (entity, collisions) in colliders.iter() {
-        for c in collisions.iter() {
-            if *c == player_entity {
-                info!("Player collected a collectible!");
-                commands.entity(entity).despawn();
-            }
-        }
+    for changed in door_events.read() {
+        info!("Door {} {}", changed.door, if changed.open { "opened" } else { "closed" });
     }
 }
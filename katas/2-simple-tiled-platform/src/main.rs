@@ -1,11 +1,21 @@
 mod game_system;
+mod tile_colliders;
+mod tiled_types;
+mod tiled_world;
 
 use bevy::prelude::*;
 use game_system::SimplePlatformGame;
 use simple_2d_camera::SimplePixel2dCameraPlugin;
 use simple_platform_player_controller::{PlayerSpawnSettings, TILE_SIZE_PIXELS};
+use tiled_types::{export_tiled_types, EXPORT_TILED_TYPES_ARG};
+
+fn main() -> AppExit {
+    let mut args = std::env::args().skip(1);
+
+    if args.next().as_deref() == Some(EXPORT_TILED_TYPES_ARG) {
+        return export_tiled_types(args.any(|arg| arg == "--check"));
+    }
 
-fn main() {
     App::new()
         .insert_resource(PlayerSpawnSettings { position: Vec2::new(2. * TILE_SIZE_PIXELS, 10. * TILE_SIZE_PIXELS) })
         .add_plugins((
@@ -13,5 +23,5 @@ fn main() {
             SimplePixel2dCameraPlugin::default(),
         ))
         .add_plugins(SimplePlatformGame)
-        .run();
+        .run()
 }
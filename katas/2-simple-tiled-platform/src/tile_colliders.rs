@@ -0,0 +1,304 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_ecs_tiled::prelude::*;
+use breakables::tiled_map::tile_hit_points;
+use physics_layers::world_collision_layers;
+use tiled::{LayerTile, Map, ObjectData, ObjectShape, Orientation, TileLayer};
+
+/// Tile collision rectangle edges closer than this, in pixels, are welded together.
+///
+/// Hides the small inaccuracies of hand-drawn collision shapes (a `15.9111` wide rectangle on a 16 pixel tile),
+/// which would otherwise leave seams for the player to catch on.
+pub const TILE_COLLIDER_WELD_TOLERANCE: f32 = 0.25;
+
+/// Static body outlining the union of every tile collision rectangle of a tile layer.
+///
+/// Only the outer edges are kept, so there are no internal edges between tiles to snag on.
+#[derive(Component, Debug)]
+pub struct MergedTileColliders;
+
+/// Whether the collider comes from a tile collision rectangle merged by [`merged_tile_colliders_observer`],
+/// in which case the physics backend shouldn't spawn it on its own.
+pub fn is_merged_tile_collider(map: &Map, collider_source: &TiledColliderSource) -> bool {
+    let TiledColliderSourceType::Tile { object_id, .. } = collider_source.ty else {
+        return false;
+    };
+
+    if map.orientation != Orientation::Orthogonal {
+        return false;
+    }
+
+    collider_source
+        .tile(map)
+        .as_ref()
+        .and_then(|tile| tile.collision.as_ref())
+        .and_then(|collision| collision.object_data().get(object_id))
+        .is_some_and(is_mergeable)
+}
+
+fn is_mergeable(object: &ObjectData) -> bool {
+    matches!(object.shape, ObjectShape::Rect { .. }) && object.rotation == 0.
+}
+
+pub fn merged_tile_colliders_observer(
+    trigger: Trigger<TiledLayerCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+) {
+    let event = trigger.event();
+    let map = event.map(&maps);
+    let layer = event.layer(&maps);
+
+    if map.orientation != Orientation::Orthogonal {
+        return;
+    }
+
+    let Some(tile_layer) = layer.as_tile_layer() else {
+        return;
+    };
+
+    let rects = tile_collision_rects(map, &tile_layer);
+    let (vertices, indices) = merge_collision_rects(&rects, TILE_COLLIDER_WELD_TOLERANCE);
+
+    if indices.is_empty() {
+        return;
+    }
+
+    commands.entity(event.layer).with_children(|parent| {
+        parent.spawn((
+            MergedTileColliders,
+            Name::new(format!("MergedTileColliders({})", layer.name)),
+            Transform::default(),
+            Collider::polyline(vertices, Some(indices)),
+            RigidBody::Static,
+            world_collision_layers(),
+            Friction::new(0.),
+        ));
+    });
+}
+
+/// Collision rectangles of every tile of the layer, in layer space.
+fn tile_collision_rects(map: &Map, tile_layer: &TileLayer) -> Vec<Rect> {
+    let tile_size = Vec2::new(map.tile_width as f32, map.tile_height as f32);
+    let mut rects = Vec::new();
+
+    for x in 0..map.width {
+        for y in 0..map.height {
+            let Some(layer_tile) = tile_layer.get_tile(x as i32, y as i32) else {
+                continue;
+            };
+            let Some(tile) = layer_tile.get_tile() else {
+                continue;
+            };
+            // Breakable tiles get their own collider, removed when they break.
+            if tile_hit_points(&tile).is_some() {
+                continue;
+            }
+            let Some(collision) = tile.collision.as_ref() else {
+                continue;
+            };
+
+            let tileset = layer_tile.get_tileset();
+            // Tiled rows go down, layer space goes up.
+            let origin = Vec2::new(x as f32, (map.height - 1 - y) as f32) * tile_size
+                + Vec2::new(tileset.offset_x as f32, -tileset.offset_y as f32);
+
+            for object in collision
+                .object_data()
+                .iter()
+                .filter(|object| is_mergeable(object))
+            {
+                let ObjectShape::Rect { width, height } = object.shape else {
+                    continue;
+                };
+
+                let rect = flip_tile_rect(
+                    Rect::new(object.x, object.y, object.x + width, object.y + height),
+                    &layer_tile,
+                    tile_size,
+                );
+
+                // Tile collision objects are in Tiled coordinates, with y going down from the tile top.
+                rects.push(Rect::new(
+                    origin.x + rect.min.x,
+                    origin.y + tile_size.y - rect.max.y,
+                    origin.x + rect.max.x,
+                    origin.y + tile_size.y - rect.min.y,
+                ));
+            }
+        }
+    }
+
+    rects
+}
+
+/// Applies the tile flags to a rectangle in Tiled tile coordinates. Diagonal flips come first, like in Tiled.
+fn flip_tile_rect(mut rect: Rect, layer_tile: &LayerTile, tile_size: Vec2) -> Rect {
+    if layer_tile.flip_d {
+        rect = Rect::new(rect.min.y, rect.min.x, rect.max.y, rect.max.x);
+    }
+
+    if layer_tile.flip_h {
+        rect = Rect::new(
+            tile_size.x - rect.max.x,
+            rect.min.y,
+            tile_size.x - rect.min.x,
+            rect.max.y,
+        );
+    }
+
+    if layer_tile.flip_v {
+        rect = Rect::new(
+            rect.min.x,
+            tile_size.y - rect.max.y,
+            rect.max.x,
+            tile_size.y - rect.min.y,
+        );
+    }
+
+    rect
+}
+
+/// Outlines the union of `rects` as polyline segments, welding edges closer than `tolerance`.
+///
+/// The welded edges split the plane into a grid of cells, cells inside any rectangle are solid, and every boundary
+/// between a solid and an empty cell becomes a segment. Collinear boundaries are joined into single segments.
+pub fn merge_collision_rects(rects: &[Rect], tolerance: f32) -> (Vec<Vec2>, Vec<[u32; 2]>) {
+    let xs = WeldedAxis::new(
+        rects.iter().flat_map(|rect| [rect.min.x, rect.max.x]),
+        tolerance,
+    );
+    let ys = WeldedAxis::new(
+        rects.iter().flat_map(|rect| [rect.min.y, rect.max.y]),
+        tolerance,
+    );
+
+    let columns = xs.values.len().saturating_sub(1);
+    let rows = ys.values.len().saturating_sub(1);
+    let mut solid = vec![false; columns * rows];
+
+    for rect in rects {
+        let (x0, x1) = (xs.index(rect.min.x), xs.index(rect.max.x));
+        let (y0, y1) = (ys.index(rect.min.y), ys.index(rect.max.y));
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                solid[y * columns + x] = true;
+            }
+        }
+    }
+
+    let is_solid = |x: usize, y: usize| x < columns && y < rows && solid[y * columns + x];
+
+    let mut segments = SegmentBuilder::default();
+
+    // Horizontal boundaries, on every grid line between two rows.
+    for y in 0..=rows {
+        let mut start = None;
+
+        for x in 0..=columns {
+            let boundary = x < columns && (y > 0 && is_solid(x, y - 1)) != is_solid(x, y);
+
+            match (boundary, start) {
+                (true, None) => start = Some(x),
+                (false, Some(x0)) => {
+                    segments.push((x0, y), (x, y));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Vertical boundaries, on every grid line between two columns.
+    for x in 0..=columns {
+        let mut start = None;
+
+        for y in 0..=rows {
+            let boundary = y < rows && (x > 0 && is_solid(x - 1, y)) != is_solid(x, y);
+
+            match (boundary, start) {
+                (true, None) => start = Some(y),
+                (false, Some(y0)) => {
+                    segments.push((x, y0), (x, y));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let vertices = segments
+        .vertices
+        .iter()
+        .map(|&(x, y)| Vec2::new(xs.values[x], ys.values[y]))
+        .collect();
+
+    (vertices, segments.indices)
+}
+
+/// Sorted coordinates along one axis, with values closer than the tolerance merged into their average.
+struct WeldedAxis {
+    /// Largest raw value of each welded group, used to look values up.
+    group_ends: Vec<f32>,
+    values: Vec<f32>,
+}
+
+impl WeldedAxis {
+    fn new(values: impl IntoIterator<Item = f32>, tolerance: f32) -> Self {
+        let mut raw: Vec<f32> = values.into_iter().collect();
+        raw.sort_by(f32::total_cmp);
+
+        let mut group_ends = Vec::new();
+        let mut welded = Vec::new();
+        let mut group: Vec<f32> = Vec::new();
+
+        for value in raw {
+            if group.first().is_some_and(|start| value - start > tolerance) {
+                group_ends.push(group[group.len() - 1]);
+                welded.push(group.iter().sum::<f32>() / group.len() as f32);
+                group.clear();
+            }
+
+            group.push(value);
+        }
+
+        if let Some(&end) = group.last() {
+            group_ends.push(end);
+            welded.push(group.iter().sum::<f32>() / group.len() as f32);
+        }
+
+        Self {
+            group_ends,
+            values: welded,
+        }
+    }
+
+    fn index(&self, value: f32) -> usize {
+        self.group_ends.partition_point(|&end| end < value)
+    }
+}
+
+#[derive(Default)]
+struct SegmentBuilder {
+    vertices: Vec<(usize, usize)>,
+    vertex_indices: HashMap<(usize, usize), u32>,
+    indices: Vec<[u32; 2]>,
+}
+
+impl SegmentBuilder {
+    fn push(&mut self, start: (usize, usize), end: (usize, usize)) {
+        let start = self.vertex(start);
+        let end = self.vertex(end);
+
+        self.indices.push([start, end]);
+    }
+
+    fn vertex(&mut self, position: (usize, usize)) -> u32 {
+        *self.vertex_indices.entry(position).or_insert_with(|| {
+            self.vertices.push(position);
+            (self.vertices.len() - 1) as u32
+        })
+    }
+}
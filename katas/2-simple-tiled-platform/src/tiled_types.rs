@@ -0,0 +1,221 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use bevy::prelude::*;
+use bevy::render::settings::WgpuSettings;
+use bevy::render::RenderPlugin;
+use bevy::window::ExitCondition;
+use bevy::winit::WinitPlugin;
+use bevy_ecs_tiled::TiledMapPluginConfig;
+use serde::Serialize;
+use serde_json::Value;
+use simple_platform_player_controller::PlayerSpawnSettings;
+
+use crate::game_system::SimplePlatformGame;
+
+/// Command line argument running [`export_tiled_types`] instead of the game, add `--check` to only compare.
+pub const EXPORT_TILED_TYPES_ARG: &str = "export-tiled-types";
+
+/// Only types of these crates, and the types they use, are exported. Everything else the game registers
+/// (avian, bevy) is noise in Tiled.
+pub const PROJECT_CRATES: &[&str] = &[
+    "simple_tiled_platform",
+    "simple_platform_player_controller",
+    "collectibles",
+    "simple_2d_camera",
+    "physics_layers",
+    "breakables",
+    "triggers",
+    "level_mechanics",
+];
+
+const TILED_TYPES_EXPORT_FILE: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tiled_types_export.json");
+const TILED_PROJECT_FILE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/assets/katas/2/map.tiled-project"
+);
+
+/// Writes the Tiled custom types of the game's reflected components to `tiled_types_export.json` and to the
+/// `propertyTypes` of the Tiled project.
+///
+/// With `check`, nothing is written and the app exits with an error if either file is stale.
+pub fn export_tiled_types(check: bool) -> AppExit {
+    let property_types = match project_property_types() {
+        Ok(property_types) => property_types,
+        Err(error) => {
+            error!("Couldn't export Tiled types: {error}");
+            return AppExit::error();
+        }
+    };
+
+    let results = [
+        sync_export_file(Path::new(TILED_TYPES_EXPORT_FILE), &property_types, check),
+        sync_project_file(Path::new(TILED_PROJECT_FILE), &property_types, check),
+    ];
+
+    let mut exit = AppExit::Success;
+
+    for result in results {
+        match result {
+            Ok(Sync::UpToDate(path)) => info!("{} is up to date", path.display()),
+            Ok(Sync::Written(path)) => info!("Wrote {}", path.display()),
+            Ok(Sync::Stale(path)) => {
+                error!(
+                    "{} is stale, run `cargo run -p simple-tiled-platform -- {EXPORT_TILED_TYPES_ARG}`",
+                    path.display()
+                );
+                exit = AppExit::error();
+            }
+            Err(error) => {
+                error!("{error}");
+                exit = AppExit::error();
+            }
+        }
+    }
+
+    exit
+}
+
+enum Sync<'a> {
+    UpToDate(&'a Path),
+    Written(&'a Path),
+    Stale(&'a Path),
+}
+
+type ExportResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+/// Builds the game headlessly and lets `bevy_ecs_tiled` export every registered type, then keeps the project ones.
+fn project_property_types() -> ExportResult<Vec<Value>> {
+    let raw_export =
+        std::env::temp_dir().join(format!("tiled_types_export.{}.json", std::process::id()));
+
+    let mut app = App::new();
+
+    app.insert_resource(PlayerSpawnSettings::default())
+        .add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    ..default()
+                })
+                .set(RenderPlugin {
+                    render_creation: WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }
+                    .into(),
+                    ..default()
+                })
+                .disable::<WinitPlugin>(),
+        )
+        .add_plugins(SimplePlatformGame)
+        .insert_resource(TiledMapPluginConfig {
+            tiled_types_export_file: Some(raw_export.clone()),
+        });
+
+    app.finish();
+    app.cleanup();
+    // The export runs at startup.
+    app.update();
+
+    let types: Vec<Value> = serde_json::from_str(&std::fs::read_to_string(&raw_export)?)?;
+    std::fs::remove_file(&raw_export)?;
+
+    Ok(filter_project_types(types))
+}
+
+/// Keeps the types of [`PROJECT_CRATES`] and the types their members use, sorted by name.
+///
+/// Ids are renumbered in that order, the exported ones follow the registry's hash order and change between builds.
+fn filter_project_types(types: Vec<Value>) -> Vec<Value> {
+    let types: BTreeMap<String, Value> = types
+        .into_iter()
+        .filter_map(|export| Some((export.get("name")?.as_str()?.to_string(), export)))
+        .collect();
+
+    let mut kept = BTreeSet::new();
+    let mut pending: Vec<&str> = types
+        .keys()
+        .filter(|name| {
+            PROJECT_CRATES
+                .iter()
+                .any(|project_crate| name.starts_with(&format!("{project_crate}::")))
+        })
+        .map(String::as_str)
+        .collect();
+
+    while let Some(name) = pending.pop() {
+        if !kept.insert(name) {
+            continue;
+        }
+
+        let members = types[name].get("members").and_then(Value::as_array);
+
+        for member in members.into_iter().flatten() {
+            if let Some(property_type) = member.get("propertyType").and_then(Value::as_str) {
+                if let Some((dependency, _)) = types.get_key_value(property_type) {
+                    pending.push(dependency);
+                }
+            }
+        }
+    }
+
+    kept.into_iter()
+        .zip(1..)
+        .map(|(name, id)| {
+            let mut export = types[name].clone();
+            export["id"] = Value::from(id);
+            export
+        })
+        .collect()
+}
+
+fn sync_export_file<'a>(
+    path: &'a Path,
+    property_types: &[Value],
+    check: bool,
+) -> ExportResult<Sync<'a>> {
+    // Same layout as `bevy_ecs_tiled`'s own export.
+    let contents = serde_json::to_string_pretty(property_types)?;
+
+    sync_file(path, contents, check)
+}
+
+fn sync_project_file<'a>(
+    path: &'a Path,
+    property_types: &[Value],
+    check: bool,
+) -> ExportResult<Sync<'a>> {
+    let mut project: Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    project["propertyTypes"] = Value::from(property_types.to_vec());
+
+    // Tiled indents its project files with 4 spaces.
+    let mut contents = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    project.serialize(&mut serde_json::Serializer::with_formatter(
+        &mut contents,
+        formatter,
+    ))?;
+    contents.push(b'\n');
+
+    sync_file(path, String::from_utf8(contents)?, check)
+}
+
+fn sync_file(path: &Path, contents: String, check: bool) -> ExportResult<Sync<'_>> {
+    let current = std::fs::read_to_string(path).unwrap_or_default();
+
+    if current == contents {
+        return Ok(Sync::UpToDate(path));
+    }
+
+    if check {
+        return Ok(Sync::Stale(path));
+    }
+
+    std::fs::write(path, contents)
+        .map_err(|error| format!("Couldn't write {}: {error}", path.display()))?;
+
+    Ok(Sync::Written(path))
+}
@@ -0,0 +1,449 @@
+use std::path::Path;
+
+use avian2d::prelude::*;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use bevy_ecs_tiled::prelude::*;
+use collectibles::Collected;
+use serde::Deserialize;
+use simple_2d_camera::{CameraRoom, PixelCamera};
+use simple_platform_player_controller::Player;
+
+/// Tiled rectangle objects with this class send the player to another map of the world.
+///
+/// The `map` string property names the target map (its file name without the `.tmx` extension), and the `spawn`
+/// string property the name of the [`TILED_MAP_SPAWN_CLASS`] object to arrive at.
+pub const TILED_MAP_DOOR_CLASS: &str = "MapDoor";
+
+/// Tiled point objects with this class are where [`TILED_MAP_DOOR_CLASS`] objects lead to, looked up by object name.
+pub const TILED_MAP_SPAWN_CLASS: &str = "MapSpawn";
+
+/// Loads Tiled `.world` files, spawning their maps around the camera as it moves.
+pub struct TiledWorldPlugin;
+
+impl Plugin for TiledWorldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<TiledWorld>()
+            .register_asset_loader(TiledWorldLoader)
+            .init_resource::<TiledWorldSettings>()
+            .init_resource::<CollectedWorldObjects>()
+            .add_observer(world_object_observer)
+            .add_observer(world_map_room_observer)
+            .add_observer(map_door_observer)
+            .add_observer(map_spawn_observer)
+            .add_systems(
+                Update,
+                (
+                    map_door_system,
+                    tiled_world_streaming_system,
+                    map_door_arrival_system,
+                    world_object_collected_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// A Tiled world: maps placed next to each other, in Bevy world space.
+///
+/// Only explicitly listed maps are supported, not file name patterns.
+#[derive(Asset, TypePath, Debug)]
+pub struct TiledWorld {
+    pub maps: Vec<TiledWorldMap>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TiledWorldMap {
+    /// File name without the `.tmx` extension, used by doors to refer to the map.
+    pub name: String,
+    pub path: String,
+    pub rect: Rect,
+}
+
+/// Spawns the maps of a [`TiledWorld`] as children of this entity.
+#[derive(Component)]
+#[require(Transform, Visibility, TiledWorldMaps)]
+pub struct TiledWorldHandle(pub Handle<TiledWorld>);
+
+#[derive(Component, Default)]
+struct TiledWorldMaps {
+    spawned: HashMap<String, Entity>,
+}
+
+/// Name of the world map this map entity was spawned for.
+#[derive(Component, Clone, Debug)]
+pub struct TiledWorldMapName(pub String);
+
+/// An object of a world map, by map name and Tiled object id.
+#[derive(Component, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TiledWorldObject {
+    pub map: String,
+    pub id: u32,
+}
+
+/// World map objects already collected, which aren't spawned again when their map streams back in.
+#[derive(Resource, Default, Debug)]
+pub struct CollectedWorldObjects(pub HashSet<TiledWorldObject>);
+
+#[derive(Resource, Clone, Debug)]
+pub struct TiledWorldSettings {
+    /// Maps closer than this to the camera view, in pixels, are spawned.
+    pub load_margin: f32,
+    /// Maps further than this from the camera view are despawned. Larger than `load_margin` so maps on the edge
+    /// don't keep coming and going.
+    pub unload_margin: f32,
+}
+
+impl Default for TiledWorldSettings {
+    fn default() -> Self {
+        Self {
+            load_margin: 256.,
+            unload_margin: 512.,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorldFile {
+    #[serde(default)]
+    maps: Vec<WorldFileMap>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WorldFileMap {
+    file_name: String,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+#[derive(Default)]
+struct TiledWorldLoader;
+
+impl AssetLoader for TiledWorldLoader {
+    type Asset = TiledWorld;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let world: WorldFile = serde_json::from_slice(&bytes)?;
+        let directory = load_context
+            .path()
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        let maps = world
+            .maps
+            .into_iter()
+            .map(|map| {
+                let path = directory.join(&map.file_name);
+                let name = Path::new(&map.file_name)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+
+                // Tiled world positions are the top left corner of the map, with y going down.
+                let min = Vec2::new(map.x, -map.y - map.height);
+
+                TiledWorldMap {
+                    name,
+                    path: path.to_string_lossy().replace('\\', "/"),
+                    rect: Rect::from_corners(min, min + Vec2::new(map.width, map.height)),
+                }
+            })
+            .collect();
+
+        Ok(TiledWorld { maps })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["world"]
+    }
+}
+
+/// Set while the player walks through a door, until they arrive at the target spawn.
+#[derive(Resource, Clone, Debug)]
+struct PendingMapDoor(MapDoor);
+
+fn tiled_world_streaming_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    settings: Res<TiledWorldSettings>,
+    worlds: Res<Assets<TiledWorld>>,
+    pending_door: Option<Res<PendingMapDoor>>,
+    camera: Query<(&Transform, &OrthographicProjection), With<PixelCamera>>,
+    mut world_query: Query<(Entity, &TiledWorldHandle, &mut TiledWorldMaps)>,
+) {
+    let Ok((camera_transform, projection)) = camera.get_single() else {
+        return;
+    };
+
+    let view = Rect::from_center_half_size(
+        camera_transform.translation.truncate(),
+        projection.area.half_size(),
+    );
+
+    for (world_entity, world_handle, mut world_maps) in world_query.iter_mut() {
+        let Some(world) = worlds.get(&world_handle.0) else {
+            continue;
+        };
+
+        for map in &world.maps {
+            let door_target = pending_door
+                .as_ref()
+                .is_some_and(|door| door.0.map == map.name);
+            let spawned = world_maps.spawned.get(&map.name).copied();
+
+            match spawned {
+                None if door_target || overlaps(map.rect, view, settings.load_margin) => {
+                    let map_entity = commands
+                        .spawn((
+                            TiledMapHandle(asset_server.load(map.path.clone())),
+                            TiledWorldMapName(map.name.clone()),
+                            Transform::from_translation(map.rect.min.extend(0.)),
+                        ))
+                        .set_parent(world_entity)
+                        .id();
+
+                    world_maps.spawned.insert(map.name.clone(), map_entity);
+                }
+                Some(map_entity)
+                    if !door_target && !overlaps(map.rect, view, settings.unload_margin) =>
+                {
+                    commands.entity(map_entity).despawn_recursive();
+                    world_maps.spawned.remove(&map.name);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn overlaps(map: Rect, view: Rect, margin: f32) -> bool {
+    !map.inflate(margin).intersect(view).is_empty()
+}
+
+/// Tags the objects of world maps, despawning the ones already collected.
+fn world_object_observer(
+    trigger: Trigger<TiledObjectCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+    collected: Res<CollectedWorldObjects>,
+    map_query: Query<&TiledWorldMapName>,
+) {
+    let event = trigger.event();
+
+    let Ok(map_name) = map_query.get(event.map) else {
+        return;
+    };
+
+    let object = TiledWorldObject {
+        map: map_name.0.clone(),
+        id: event.object(&maps).id(),
+    };
+
+    if collected.0.contains(&object) {
+        commands.entity(event.object).despawn_recursive();
+    } else {
+        commands.entity(event.object).insert(object);
+    }
+}
+
+fn world_object_collected_system(
+    mut collected_events: EventReader<Collected>,
+    mut collected: ResMut<CollectedWorldObjects>,
+    objects: Query<&TiledWorldObject>,
+) {
+    for event in collected_events.read() {
+        if let Ok(object) = objects.get(event.collectible) {
+            collected.0.insert(object.clone());
+        }
+    }
+}
+
+/// Confines the camera to each world map, like the rooms of a level.
+fn world_map_room_observer(
+    trigger: Trigger<TiledMapCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+    map_query: Query<&Transform, With<TiledWorldMapName>>,
+) {
+    let event = trigger.event();
+
+    let Ok(map_transform) = map_query.get(event.map) else {
+        return;
+    };
+
+    let map = event.map(&maps);
+    let min = map_transform.translation.truncate();
+    let size = Vec2::new(
+        (map.width * map.tile_width) as f32,
+        (map.height * map.tile_height) as f32,
+    );
+
+    commands
+        .entity(event.map)
+        .insert(CameraRoom(Rect::from_corners(min, min + size)));
+}
+
+/// Sends the player to the `spawn` object of the `map` world map when touched.
+#[derive(Component, Clone, Debug)]
+pub struct MapDoor {
+    pub map: String,
+    pub spawn: String,
+}
+
+/// Where [`MapDoor`]s leading to `name` in `map` place the player, in world space.
+#[derive(Component, Clone, Debug)]
+pub struct MapSpawn {
+    pub map: String,
+    pub name: String,
+    pub position: Vec2,
+}
+
+/// Whether the collider comes from a [`TILED_MAP_DOOR_CLASS`] object, which should be a sensor rather than a wall.
+pub fn is_map_door_collider(map: &tiled::Map, collider_source: &TiledColliderSource) -> bool {
+    collider_source
+        .object(map)
+        .is_some_and(|object| object.user_type == TILED_MAP_DOOR_CLASS)
+}
+
+fn map_door_observer(
+    trigger: Trigger<TiledObjectCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+) {
+    let event = trigger.event();
+    let object = event.object(&maps);
+
+    if object.user_type != TILED_MAP_DOOR_CLASS {
+        return;
+    }
+
+    let property = |name: &str| match object.properties.get(name) {
+        Some(tiled::PropertyValue::StringValue(value)) => Some(value.clone()),
+        _ => None,
+    };
+
+    let (Some(map), Some(spawn)) = (property("map"), property("spawn")) else {
+        warn!("Map door {} needs map and spawn properties", object.name);
+        return;
+    };
+
+    commands.entity(event.object).insert(MapDoor { map, spawn });
+}
+
+fn map_spawn_observer(
+    trigger: Trigger<TiledObjectCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+    map_query: Query<(&Transform, &TiledWorldMapName)>,
+) {
+    let event = trigger.event();
+    let object = event.object(&maps);
+
+    if object.user_type != TILED_MAP_SPAWN_CLASS {
+        return;
+    }
+
+    let Ok((map_transform, map_name)) = map_query.get(event.map) else {
+        return;
+    };
+
+    let position = map_transform
+        .transform_point(event.world_position(&maps).extend(0.))
+        .truncate();
+
+    commands.entity(event.object).insert(MapSpawn {
+        map: map_name.0.clone(),
+        name: object.name.clone(),
+        position,
+    });
+}
+
+fn map_door_system(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionStarted>,
+    pending_door: Option<Res<PendingMapDoor>>,
+    parents: Query<&Parent>,
+    doors: Query<&MapDoor>,
+    players: Query<(), With<Player>>,
+) {
+    for CollisionStarted(a, b) in collision_events.read() {
+        if pending_door.is_some() {
+            continue;
+        }
+
+        // Door colliders are spawned as children of the door objects.
+        let door = |collider: Entity| {
+            parents
+                .get(collider)
+                .ok()
+                .and_then(|parent| doors.get(parent.get()).ok())
+        };
+
+        let door = match (door(*a), door(*b)) {
+            (Some(door), _) if players.contains(*b) => door,
+            (_, Some(door)) if players.contains(*a) => door,
+            _ => continue,
+        };
+
+        commands.insert_resource(PendingMapDoor(door.clone()));
+    }
+}
+
+type ArrivingPlayerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static mut Transform, &'static mut LinearVelocity),
+    (With<Player>, Without<PixelCamera>),
+>;
+
+fn map_door_arrival_system(
+    mut commands: Commands,
+    pending_door: Option<Res<PendingMapDoor>>,
+    spawns: Query<&MapSpawn>,
+    mut player: ArrivingPlayerQuery,
+    mut camera: Query<&mut Transform, With<PixelCamera>>,
+) {
+    let Some(pending_door) = pending_door else {
+        return;
+    };
+
+    let door = &pending_door.0;
+
+    // The target map may still be loading.
+    let Some(spawn) = spawns
+        .iter()
+        .find(|spawn| spawn.map == door.map && spawn.name == door.spawn)
+    else {
+        return;
+    };
+
+    for (mut transform, mut velocity) in player.iter_mut() {
+        transform.translation.x = spawn.position.x;
+        transform.translation.y = spawn.position.y;
+        velocity.0 = Vec2::ZERO;
+    }
+
+    for mut transform in camera.iter_mut() {
+        transform.translation.x = spawn.position.x;
+        transform.translation.y = spawn.position.y;
+    }
+
+    commands.remove_resource::<PendingMapDoor>();
+}
@@ -0,0 +1,163 @@
+//! Compares one collider per `Wall` cell against merged wall colliders on the nexus map.
+//!
+//! Run with `cargo bench -p gamejam --bench wall_colliders`.
+
+use std::time::{Duration, Instant};
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_ecs_ldtk::ldtk::LdtkJson;
+use bevy_ecs_ldtk::utils::grid_coords_to_translation_relative_to_tile_layer;
+use bevy_ecs_ldtk::GridCoords;
+use gamejam::systems::walls::{merge_wall_cells, wall_collider};
+
+const MAP_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/assets/maps/grayboxes/nexus_ldtk/nexus.ldtk"
+);
+const COLLISION_LAYER: &str = "Collision";
+const WALL_VALUE: i32 = 1;
+const BODIES: usize = 64;
+const WARMUP_STEPS: u32 = 64;
+const STEPS: u32 = 600;
+
+fn main() {
+    let (cells, grid_size) = load_wall_cells();
+
+    let per_cell: Vec<IRect> = cells
+        .iter()
+        .map(|cell| IRect::new(cell.x, cell.y, cell.x + 1, cell.y + 1))
+        .collect();
+    let merged = merge_wall_cells(cells.iter().copied());
+
+    check_alignment(&merged, grid_size);
+
+    println!(
+        "nexus: {} wall cells, {} dynamic bodies, {} steps",
+        cells.len(),
+        BODIES,
+        STEPS
+    );
+
+    run("per cell", &per_cell, grid_size);
+    run("merged", &merged, grid_size);
+}
+
+/// Wall cells of the first level, in the same bottom-up [`GridCoords`](bevy_ecs_ldtk::GridCoords) as the game.
+fn load_wall_cells() -> (Vec<IVec2>, f32) {
+    let file = std::fs::read_to_string(MAP_PATH).expect("nexus map should be readable");
+    let project: LdtkJson = serde_json::from_str(&file).expect("nexus map should be valid LDtk");
+
+    let layer = project
+        .levels
+        .first()
+        .and_then(|level| level.layer_instances.as_ref())
+        .and_then(|layers| {
+            layers
+                .iter()
+                .find(|layer| layer.identifier == COLLISION_LAYER)
+        })
+        .expect("nexus map should have a collision layer");
+
+    let cells = layer
+        .int_grid_csv
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| **value == WALL_VALUE)
+        .map(|(index, _)| {
+            let index = index as i32;
+            IVec2::new(index % layer.c_wid, layer.c_hei - 1 - index / layer.c_wid)
+        })
+        .collect();
+
+    (cells, layer.grid_size as f32)
+}
+
+/// Checks that merged colliders are centered on the cell entities they replace, as placed by bevy_ecs_ldtk.
+fn check_alignment(walls: &[IRect], grid_size: f32) {
+    let mut world = World::new();
+
+    for &rect in walls {
+        let entity = world.spawn(wall_collider(rect, grid_size)).id();
+        let translation = world
+            .get::<Transform>(entity)
+            .unwrap()
+            .translation
+            .truncate();
+
+        let cells = (rect.min.x..rect.max.x)
+            .flat_map(|x| (rect.min.y..rect.max.y).map(move |y| GridCoords::new(x, y)));
+        let cell_center = cells
+            .map(|coords| {
+                grid_coords_to_translation_relative_to_tile_layer(
+                    coords,
+                    IVec2::splat(grid_size as i32),
+                )
+            })
+            .sum::<Vec2>()
+            / (rect.width() * rect.height()) as f32;
+
+        assert!(
+            translation.abs_diff_eq(cell_center, 1e-3),
+            "collider of {rect:?} at {translation} should be centered on its cells at {cell_center}"
+        );
+    }
+}
+
+fn run(name: &str, walls: &[IRect], grid_size: f32) {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        PhysicsPlugins::default()
+            .with_length_unit(16.)
+            .build()
+            .disable::<ColliderHierarchyPlugin>(),
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+        1. / 64.,
+    )))
+    .insert_resource(Gravity(Vec2::new(0., -9.81 * 32.)));
+
+    let world = app.world_mut();
+
+    for &rect in walls {
+        world.spawn(wall_collider(rect, grid_size));
+    }
+
+    let bounds = walls
+        .iter()
+        .fold(IRect::EMPTY, |bounds, rect| bounds.union(*rect))
+        .as_rect();
+
+    for index in 0..BODIES {
+        let x = bounds.min.x + (index as f32 + 0.5) / BODIES as f32 * bounds.width();
+
+        world.spawn((
+            Transform::from_xyz(x * grid_size, bounds.max.y * grid_size, 0.),
+            RigidBody::Dynamic,
+            Collider::rectangle(12., 12.),
+            LinearVelocity(Vec2::new(if index % 2 == 0 { 60. } else { -60. }, 0.)),
+        ));
+    }
+
+    for _ in 0..WARMUP_STEPS {
+        app.update();
+    }
+
+    let start = Instant::now();
+
+    for _ in 0..STEPS {
+        app.update();
+    }
+
+    let step_time = start.elapsed().as_secs_f64() * 1000. / STEPS as f64;
+
+    println!(
+        "{name:>8}: {:>5} wall colliders, {:>5} entities, {step_time:.3} ms/step",
+        walls.len(),
+        app.world().entities().len(),
+    );
+}
@@ -1,8 +1,6 @@
-mod systems;
-
 use bevy::prelude::*;
 use simple_2d_camera::SimplePixel2dCameraPlugin;
-use crate::systems::init_game::SimplePlatformGame;
+use gamejam::systems::init_game::SimplePlatformGame;
 
 fn main() {
     App::new()
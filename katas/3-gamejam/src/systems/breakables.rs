@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+use breakables::{BreakableHit, TileBroken};
+use collectibles::Collectible;
+use gamejam_platform_controller::attacks::AttackHit;
+
+const DROP_COLOR: Color = Color::srgb(1., 0.85, 0.2);
+const DROP_SIZE: f32 = 6.;
+
+/// Attacks hitting the world damage breakable tiles, the breakable plugin ignores other walls.
+pub fn attack_breakable_system(
+    mut attack_hits: EventReader<AttackHit>,
+    mut breakable_hits: EventWriter<BreakableHit>,
+) {
+    for hit in attack_hits.read().filter(|hit| hit.hit_world) {
+        breakable_hits.send(BreakableHit {
+            target: hit.target,
+            damage: 1,
+        });
+    }
+}
+
+/// Broken tiles drop a coin.
+pub fn breakable_drop_system(mut commands: Commands, mut broken_events: EventReader<TileBroken>) {
+    for broken in broken_events.read() {
+        commands.spawn((
+            Collectible::default(),
+            Sprite::from_color(DROP_COLOR, Vec2::splat(DROP_SIZE)),
+            Transform::from_translation(broken.position.extend(1.)),
+        ));
+    }
+}
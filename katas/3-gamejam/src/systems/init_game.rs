@@ -1,8 +1,17 @@
+use crate::systems::breakables::{attack_breakable_system, breakable_drop_system};
+use crate::systems::ldtk_components::LdtkComponentsPlugin;
+use crate::systems::level_transitions::LevelTransitionPlugin;
+use crate::systems::switches::attack_switch_system;
+use crate::systems::walls::{wall_collider_system, WallBundle};
 use avian2d::prelude::*;
 use avian2d::PhysicsPlugins;
 use bevy::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
+use breakables::BreakablePlugin;
+use collectibles::CollectiblePlugin;
 use gamejam_platform_controller::{GameStates, PlayerPlugin, PlayerSpawnSettings};
+use level_mechanics::LevelMechanicsPlugin;
+use triggers::TriggerPlugin;
 
 pub struct SimplePlatformGame;
 
@@ -14,12 +23,27 @@ impl Plugin for SimplePlatformGame {
         .add_plugins((
             PhysicsPlugins::default().with_length_unit(16.),
             PlayerPlugin,
+            CollectiblePlugin,
             LdtkPlugin,
+            LdtkComponentsPlugin::default(),
+            LevelTransitionPlugin,
+            TriggerPlugin,
+            LevelMechanicsPlugin,
+            BreakablePlugin,
         ))
         .insert_resource(LevelSelection::index(0))
         .register_ldtk_int_cell::<WallBundle>(1)
         .add_systems(Startup, start_simple_platform_game)
-        .add_systems(Update, (wall_spawn_system).run_if(in_state(GameStates::GameLoop)))
+        .add_systems(
+            Update,
+            (
+                wall_collider_system,
+                attack_switch_system,
+                attack_breakable_system,
+                breakable_drop_system,
+            )
+                .run_if(in_state(GameStates::GameLoop)),
+        )
         .insert_resource(Gravity(Vec2::new(0., -9.81 * 32.)));
 
         #[cfg(feature = "avian-debug")]
@@ -27,29 +51,6 @@ impl Plugin for SimplePlatformGame {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Component)]
-pub struct Wall;
-
-#[derive(Clone, Debug, Default, Bundle, LdtkIntCell)]
-pub struct WallBundle {
-    wall: Wall,
-}
-
-fn wall_spawn_system(
-    mut commands: Commands,
-    wall_query: Query<(Entity, &Wall, &GridCoords), Added<Wall>>,
-) {
-    for (entity, _wall, _coords) in wall_query.iter() {
-        commands.entity(entity).insert((
-            Collider::rectangle(16., 16.),
-            CollisionLayers::new(0b00100, 0b00101),
-            CollidingEntities::default(),
-            RigidBody::Static,
-            Friction::new(0.),
-        ));
-    }
-}
-
 fn start_simple_platform_game(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.spawn(LdtkWorldBundle {
         ldtk_handle: asset_server
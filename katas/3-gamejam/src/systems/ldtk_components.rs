@@ -0,0 +1,269 @@
+use std::any::TypeId;
+
+use bevy::prelude::*;
+use bevy::reflect::{DynamicEnum, DynamicVariant, GetTypeRegistration, ReflectMut, ReflectRef};
+use bevy::utils::HashMap;
+use bevy_ecs_ldtk::ldtk::{FieldInstance, FieldValue};
+use bevy_ecs_ldtk::prelude::*;
+
+/// Inserts reflected components on LDtk entities, filled in from their field instances.
+///
+/// An entity gets the component registered for its identifier with
+/// [`register_ldtk_component`](LdtkComponentAppExt::register_ldtk_component). With `match_short_type_paths`, other
+/// entities get the reflected component whose short type path is their identifier, so a `Checkpoint` entity becomes a
+/// `Checkpoint` component.
+///
+/// Components need `#[reflect(Component, Default)]`. Each field instance sets the struct field of the same name, in
+/// `snake_case` if needed:
+/// - ints set integer and float fields, floats set float fields, bools set `bool` fields
+/// - strings and file paths set `String` fields, colors set `Color` fields
+/// - enums set unit variants of enum fields, or `String` fields
+/// - points set `IVec2` fields to the grid cell, or `Vec2` fields to the offset from the entity center in pixels
+/// - entity refs set [`EntityIid`] or `String` fields
+///
+/// Null values and array fields leave the default value.
+#[derive(Default)]
+pub struct LdtkComponentsPlugin {
+    /// Also build components for entities that weren't registered, from any reflected component named like them.
+    pub match_short_type_paths: bool,
+}
+
+impl Plugin for LdtkComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.world_mut()
+            .get_resource_or_init::<LdtkComponents>()
+            .match_short_type_paths = self.match_short_type_paths;
+        app.add_systems(Update, ldtk_components_system);
+    }
+}
+
+/// Components explicitly registered for LDtk entity identifiers.
+#[derive(Resource, Default, Debug)]
+pub struct LdtkComponents {
+    identifiers: HashMap<String, TypeId>,
+    match_short_type_paths: bool,
+}
+
+pub trait LdtkComponentAppExt {
+    /// Inserts a `T` on every LDtk entity named `identifier`, see [`LdtkComponentsPlugin`].
+    fn register_ldtk_component<T: Component + Reflect + GetTypeRegistration>(
+        &mut self,
+        identifier: &str,
+    ) -> &mut Self;
+}
+
+impl LdtkComponentAppExt for App {
+    fn register_ldtk_component<T: Component + Reflect + GetTypeRegistration>(
+        &mut self,
+        identifier: &str,
+    ) -> &mut Self {
+        self.register_type::<T>();
+        self.world_mut()
+            .get_resource_or_init::<LdtkComponents>()
+            .identifiers
+            .insert(identifier.to_string(), TypeId::of::<T>());
+        self
+    }
+}
+
+fn ldtk_components_system(
+    mut commands: Commands,
+    components: Res<LdtkComponents>,
+    type_registry: Res<AppTypeRegistry>,
+    entities: Query<(Entity, &EntityInstance, Option<&Parent>), Added<EntityInstance>>,
+    layers: Query<&LayerMetadata>,
+) {
+    let type_registry = type_registry.read();
+
+    for (entity, entity_instance, parent) in entities.iter() {
+        let registration = match components.identifiers.get(&entity_instance.identifier) {
+            Some(type_id) => type_registry.get(*type_id),
+            None if components.match_short_type_paths => type_registry
+                .get_with_short_type_path(&entity_instance.identifier)
+                .inspect(|registration| {
+                    debug!(
+                        "LDtk entity {} matched {} by its short type path",
+                        entity_instance.identifier,
+                        registration.type_info().type_path()
+                    );
+                }),
+            None => continue,
+        };
+
+        let Some(registration) =
+            registration.filter(|registration| registration.data::<ReflectComponent>().is_some())
+        else {
+            continue;
+        };
+
+        let Some(reflect_default) = registration.data::<ReflectDefault>() else {
+            warn!(
+                "{} can't be built for LDtk entity {}, it doesn't reflect Default",
+                registration.type_info().type_path(),
+                entity_instance.identifier
+            );
+            continue;
+        };
+
+        let grid_size = parent
+            .and_then(|parent| layers.get(parent.get()).ok())
+            .map_or(1., |layer| layer.grid_size as f32);
+
+        let mut component = reflect_default.default();
+
+        for field_instance in &entity_instance.field_instances {
+            apply_field_instance(
+                component.as_mut(),
+                field_instance,
+                entity_instance,
+                grid_size,
+            );
+        }
+
+        let type_id = registration.type_id();
+
+        // Entities registered with a bundle may already have the component.
+        commands.queue(move |world: &mut World| {
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let type_registry = type_registry.read();
+
+            let Some(reflect_component) = type_registry.get_type_data::<ReflectComponent>(type_id)
+            else {
+                return;
+            };
+            let Ok(mut entity) = world.get_entity_mut(entity) else {
+                return;
+            };
+
+            if !entity.contains_type_id(type_id) {
+                reflect_component.insert(
+                    &mut entity,
+                    component.as_partial_reflect(),
+                    &type_registry,
+                );
+            }
+        });
+    }
+}
+
+fn apply_field_instance(
+    component: &mut dyn Reflect,
+    field_instance: &FieldInstance,
+    entity_instance: &EntityInstance,
+    grid_size: f32,
+) {
+    let ReflectMut::Struct(component) = component.reflect_mut() else {
+        return;
+    };
+
+    let identifier = &field_instance.identifier;
+    let snake_case = to_snake_case(identifier);
+
+    let name = if component.field(identifier).is_some() {
+        identifier
+    } else {
+        &snake_case
+    };
+
+    let Some(field) = component.field_mut(name) else {
+        return;
+    };
+
+    let Some(value) = field_value(field, &field_instance.value, entity_instance, grid_size) else {
+        return;
+    };
+
+    if let Err(error) = field.try_apply(value.as_ref()) {
+        warn!(
+            "LDtk field {identifier} of {} doesn't fit its component field: {error}",
+            entity_instance.identifier
+        );
+    }
+}
+
+/// Converts an LDtk field value to the type of the component `field`, if supported.
+fn field_value(
+    field: &dyn PartialReflect,
+    value: &FieldValue,
+    entity_instance: &EntityInstance,
+    grid_size: f32,
+) -> Option<Box<dyn PartialReflect>> {
+    let value: Box<dyn PartialReflect> = match value {
+        FieldValue::Int(Some(value)) => int_value(field, *value)?,
+        FieldValue::Float(Some(value)) if field.represents::<f32>() => Box::new(*value),
+        FieldValue::Float(Some(value)) if field.represents::<f64>() => Box::new(*value as f64),
+        FieldValue::Bool(value) if field.represents::<bool>() => Box::new(*value),
+        FieldValue::String(Some(value)) | FieldValue::FilePath(Some(value))
+            if field.represents::<String>() =>
+        {
+            Box::new(value.clone())
+        }
+        FieldValue::Color(value) if field.represents::<Color>() => Box::new(*value),
+        FieldValue::Enum(Some(variant)) if field.represents::<String>() => {
+            Box::new(variant.clone())
+        }
+        FieldValue::Enum(Some(variant)) if matches!(field.reflect_ref(), ReflectRef::Enum(_)) => {
+            Box::new(DynamicEnum::new(variant.clone(), DynamicVariant::Unit))
+        }
+        FieldValue::Point(Some(point)) if field.represents::<IVec2>() => Box::new(*point),
+        FieldValue::Point(Some(point)) if field.represents::<Vec2>() => {
+            // Points are grid cells counted down from the top, while the entity position is its pivot pixel and
+            // bevy_ecs_ldtk places the entity at its center.
+            let size = IVec2::new(entity_instance.width, entity_instance.height).as_vec2();
+            let entity_center =
+                entity_instance.px.as_vec2() + size * (Vec2::splat(0.5) - entity_instance.pivot);
+            let center = (point.as_vec2() + 0.5) * grid_size;
+            let offset = center - entity_center;
+            Box::new(Vec2::new(offset.x, -offset.y))
+        }
+        FieldValue::EntityRef(Some(reference)) if field.represents::<EntityIid>() => {
+            Box::new(EntityIid::new(reference.entity_iid.clone()))
+        }
+        FieldValue::EntityRef(Some(reference)) if field.represents::<String>() => {
+            Box::new(reference.entity_iid.clone())
+        }
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+fn int_value(field: &dyn PartialReflect, value: i32) -> Option<Box<dyn PartialReflect>> {
+    let value: Box<dyn PartialReflect> = if field.represents::<i32>() {
+        Box::new(value)
+    } else if field.represents::<i64>() {
+        Box::new(value as i64)
+    } else if field.represents::<u32>() {
+        Box::new(u32::try_from(value).ok()?)
+    } else if field.represents::<u16>() {
+        Box::new(u16::try_from(value).ok()?)
+    } else if field.represents::<u8>() {
+        Box::new(u8::try_from(value).ok()?)
+    } else if field.represents::<usize>() {
+        Box::new(usize::try_from(value).ok()?)
+    } else if field.represents::<f32>() {
+        Box::new(value as f32)
+    } else {
+        return None;
+    };
+
+    Some(value)
+}
+
+/// `MaxHealth` or `maxHealth` to `max_health`.
+fn to_snake_case(identifier: &str) -> String {
+    let mut snake_case = String::with_capacity(identifier.len() + 4);
+
+    for (index, character) in identifier.chars().enumerate() {
+        if character.is_uppercase() {
+            if index > 0 && !snake_case.ends_with('_') {
+                snake_case.push('_');
+            }
+            snake_case.extend(character.to_lowercase());
+        } else {
+            snake_case.push(character);
+        }
+    }
+
+    snake_case
+}
@@ -0,0 +1,291 @@
+use crate::systems::ldtk_components::LdtkComponentAppExt;
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+use gamejam_platform_controller::player_components::Player;
+use gamejam_platform_controller::GameStates;
+use physics_layers::sensor_collision_layers;
+use simple_2d_camera::ActiveCameraRoom;
+
+/// LDtk entities named `LevelExit` become [`LevelExit`]s covering the entity.
+///
+/// The `destination` entity reference field points at the entity the player arrives at, usually a `LevelEntry`.
+pub const LDTK_LEVEL_EXIT_IDENTIFIER: &str = "LevelExit";
+
+/// LDtk entities named `LevelEntry` mark where players arrive through a [`LevelExit`].
+pub const LDTK_LEVEL_ENTRY_IDENTIFIER: &str = "LevelEntry";
+
+/// Moves between LDtk levels through [`LevelExit`]s, fading the screen out and back in.
+///
+/// Levels are spawned at their world position along with their neighbours, so walking into a neighbouring level
+/// doesn't have to wait for it to spawn.
+pub struct LevelTransitionPlugin;
+
+impl Plugin for LevelTransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(LdtkSettings {
+            level_spawn_behavior: LevelSpawnBehavior::UseWorldTranslation {
+                load_level_neighbors: true,
+            },
+            ..default()
+        })
+        .init_resource::<LevelTransitionSettings>()
+        .init_resource::<LevelTransition>()
+        .register_ldtk_entity::<LevelExitBundle>(LDTK_LEVEL_EXIT_IDENTIFIER)
+        .register_ldtk_component::<LevelEntry>(LDTK_LEVEL_ENTRY_IDENTIFIER)
+        .add_systems(Startup, spawn_fade_overlay)
+        .add_systems(
+            Update,
+            (
+                level_exit_system,
+                level_fade_system,
+                level_arrival_system,
+                fade_overlay_system,
+            )
+                .chain()
+                .run_if(in_state(GameStates::GameLoop)),
+        );
+    }
+}
+
+/// Sends the player to `entry` in `level` when touched.
+#[derive(Component, Clone, Debug)]
+pub struct LevelExit {
+    pub level: LevelIid,
+    pub entry: EntityIid,
+}
+
+#[derive(Component, Reflect, Clone, Copy, Debug, Default)]
+#[reflect(Component, Default)]
+pub struct LevelEntry;
+
+#[derive(Resource, Clone, Debug)]
+pub struct LevelTransitionSettings {
+    /// Duration of the fade out, and of the fade back in.
+    pub fade_seconds: f32,
+    pub fade_color: Color,
+}
+
+impl Default for LevelTransitionSettings {
+    fn default() -> Self {
+        Self {
+            fade_seconds: 0.25,
+            fade_color: Color::BLACK,
+        }
+    }
+}
+
+/// Progress of the current level switch. Physics is paused from the moment an exit is touched until the player
+/// has been moved to the entry.
+#[derive(Resource, Default, Debug)]
+pub enum LevelTransition {
+    #[default]
+    Idle,
+    FadingOut {
+        exit: LevelExit,
+        timer: Timer,
+    },
+    Loading {
+        exit: LevelExit,
+        level_ready: bool,
+    },
+    FadingIn {
+        timer: Timer,
+    },
+}
+
+impl LevelTransition {
+    pub fn is_active(&self) -> bool {
+        !matches!(self, LevelTransition::Idle)
+    }
+}
+
+#[derive(Bundle, LdtkEntity)]
+struct LevelExitBundle {
+    #[with(level_exit_from_fields)]
+    exit: LevelExit,
+    #[with(level_exit_sensor)]
+    sensor: (Collider, Sensor, CollisionLayers),
+}
+
+fn level_exit_from_fields(entity_instance: &EntityInstance) -> LevelExit {
+    match entity_instance.get_entity_ref_field("destination") {
+        Ok(destination) => LevelExit {
+            level: LevelIid::new(destination.level_iid.clone()),
+            entry: EntityIid::new(destination.entity_iid.clone()),
+        },
+        Err(_) => {
+            warn!(
+                "Level exit {} has no destination, it won't lead anywhere",
+                entity_instance.iid
+            );
+            LevelExit {
+                level: LevelIid::new(String::new()),
+                entry: EntityIid::new(String::new()),
+            }
+        }
+    }
+}
+
+fn level_exit_sensor(entity_instance: &EntityInstance) -> (Collider, Sensor, CollisionLayers) {
+    (
+        Collider::rectangle(entity_instance.width as f32, entity_instance.height as f32),
+        Sensor,
+        sensor_collision_layers(),
+    )
+}
+
+#[derive(Component)]
+struct FadeOverlay;
+
+fn spawn_fade_overlay(mut commands: Commands) {
+    commands.spawn((
+        FadeOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        GlobalZIndex(i32::MAX),
+    ));
+}
+
+fn level_exit_system(
+    mut collision_events: EventReader<CollisionStarted>,
+    settings: Res<LevelTransitionSettings>,
+    mut transition: ResMut<LevelTransition>,
+    mut physics_time: ResMut<Time<Physics>>,
+    mut active_room: ResMut<ActiveCameraRoom>,
+    exits: Query<&LevelExit>,
+    players: Query<(), With<Player>>,
+) {
+    for CollisionStarted(a, b) in collision_events.read() {
+        // Exits touched while arriving somewhere are ignored, so entries can sit on exits leading back.
+        if transition.is_active() {
+            continue;
+        }
+
+        let exit = match (exits.get(*a), exits.get(*b)) {
+            (Ok(exit), _) if players.contains(*b) => exit,
+            (_, Ok(exit)) if players.contains(*a) => exit,
+            _ => continue,
+        };
+
+        if exit.level.as_str().is_empty() {
+            continue;
+        }
+
+        // A room slide in progress would unpause physics when done, so the level transition takes over its pause.
+        active_room.cancel_transition();
+        physics_time.pause();
+
+        *transition = LevelTransition::FadingOut {
+            exit: exit.clone(),
+            timer: Timer::from_seconds(settings.fade_seconds, TimerMode::Once),
+        };
+    }
+}
+
+fn level_fade_system(
+    time: Res<Time>,
+    mut transition: ResMut<LevelTransition>,
+    mut level_selection: ResMut<LevelSelection>,
+    levels: Query<&LevelIid>,
+) {
+    let transition = transition.as_mut();
+
+    match transition {
+        LevelTransition::FadingOut { exit, timer } => {
+            timer.tick(time.delta());
+
+            if !timer.finished() {
+                return;
+            }
+
+            // Neighbouring levels are already spawned and placed, others have to finish spawning first.
+            let level_ready = levels.iter().any(|level| *level == exit.level);
+
+            *level_selection = LevelSelection::Iid(exit.level.clone());
+            *transition = LevelTransition::Loading {
+                exit: exit.clone(),
+                level_ready,
+            };
+        }
+        LevelTransition::FadingIn { timer } => {
+            timer.tick(time.delta());
+
+            if timer.finished() {
+                *transition = LevelTransition::Idle;
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Moves the player to the exit's entry once its level is in place.
+fn level_arrival_system(
+    settings: Res<LevelTransitionSettings>,
+    mut transition: ResMut<LevelTransition>,
+    mut level_events: EventReader<LevelEvent>,
+    mut physics_time: ResMut<Time<Physics>>,
+    mut active_room: ResMut<ActiveCameraRoom>,
+    entries: Query<(&EntityIid, &GlobalTransform)>,
+    mut player: Query<(&mut Transform, &mut LinearVelocity), With<Player>>,
+) {
+    let LevelTransition::Loading { exit, level_ready } = transition.as_mut() else {
+        level_events.clear();
+        return;
+    };
+
+    for level_event in level_events.read() {
+        if let LevelEvent::Transformed(level) = level_event {
+            *level_ready |= *level == exit.level;
+        }
+    }
+
+    if !*level_ready {
+        return;
+    }
+
+    match entries.iter().find(|(iid, _)| **iid == exit.entry) {
+        Some((_, entry_transform)) => {
+            let position = entry_transform.translation().truncate();
+
+            for (mut transform, mut velocity) in player.iter_mut() {
+                transform.translation.x = position.x;
+                transform.translation.y = position.y;
+                velocity.0 = Vec2::ZERO;
+            }
+
+            // Cut the camera straight to the new level's room instead of sliding over from the old one.
+            active_room.room = None;
+            active_room.cancel_transition();
+        }
+        None => warn!("Level exit entry {} not found", exit.entry.as_str()),
+    }
+
+    physics_time.unpause();
+
+    *transition = LevelTransition::FadingIn {
+        timer: Timer::from_seconds(settings.fade_seconds, TimerMode::Once),
+    };
+}
+
+fn fade_overlay_system(
+    settings: Res<LevelTransitionSettings>,
+    transition: Res<LevelTransition>,
+    mut overlay: Query<&mut BackgroundColor, With<FadeOverlay>>,
+) {
+    let alpha = match transition.as_ref() {
+        LevelTransition::Idle => 0.,
+        LevelTransition::FadingOut { timer, .. } => timer.fraction(),
+        LevelTransition::Loading { .. } => 1.,
+        LevelTransition::FadingIn { timer } => timer.fraction_remaining(),
+    };
+
+    for mut background in overlay.iter_mut() {
+        background.0 = settings.fade_color.with_alpha(alpha);
+    }
+}
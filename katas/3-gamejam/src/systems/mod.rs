@@ -1 +1,6 @@
-pub mod init_game;
\ No newline at end of file
+pub mod breakables;
+pub mod init_game;
+pub mod ldtk_components;
+pub mod level_transitions;
+pub mod switches;
+pub mod walls;
\ No newline at end of file
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use gamejam_platform_controller::attacks::AttackHit;
+use level_mechanics::SwitchHit;
+
+/// Attacks toggle the switches they hit, the mechanics plugin ignores anything else.
+pub fn attack_switch_system(
+    mut attack_hits: EventReader<AttackHit>,
+    mut switch_hits: EventWriter<SwitchHit>,
+) {
+    for hit in attack_hits.read() {
+        switch_hits.send(SwitchHit { target: hit.target });
+    }
+}
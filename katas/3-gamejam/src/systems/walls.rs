@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+use bevy_ecs_ldtk::prelude::*;
+use physics_layers::world_collision_layers;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Component)]
+pub struct Wall;
+
+#[derive(Clone, Debug, Default, Bundle, LdtkIntCell)]
+pub struct WallBundle {
+    wall: Wall,
+}
+
+/// A static collider covering several merged [`Wall`] cells, spawned as a child of their IntGrid layer.
+#[derive(Copy, Clone, Debug, Component)]
+pub struct WallCollider {
+    /// Merged cells, with `max` exclusive.
+    pub cells: IRect,
+}
+
+/// Greedily merges grid cells into rectangles, with `max` exclusive.
+///
+/// Cells are first grouped into horizontal runs per row, and runs spanning the same columns on consecutive rows are
+/// stacked into a single rectangle.
+pub fn merge_wall_cells(cells: impl IntoIterator<Item = IVec2>) -> Vec<IRect> {
+    let mut rows: BTreeMap<i32, Vec<i32>> = BTreeMap::new();
+
+    for cell in cells {
+        rows.entry(cell.y).or_default().push(cell.x);
+    }
+
+    let mut merged = Vec::new();
+    let mut open: HashMap<(i32, i32), IRect> = HashMap::new();
+
+    for (y, mut columns) in rows {
+        columns.sort_unstable();
+        columns.dedup();
+
+        let mut next_open = HashMap::new();
+
+        for run in column_runs(&columns) {
+            let rect = match open.remove(&run) {
+                Some(mut rect) if rect.max.y == y => {
+                    rect.max.y = y + 1;
+                    rect
+                }
+                Some(rect) => {
+                    merged.push(rect);
+                    IRect::new(run.0, y, run.1, y + 1)
+                }
+                None => IRect::new(run.0, y, run.1, y + 1),
+            };
+
+            next_open.insert(run, rect);
+        }
+
+        merged.extend(open.drain().map(|(_, rect)| rect));
+        open = next_open;
+    }
+
+    merged.extend(open.into_values());
+    merged
+}
+
+/// Splits sorted columns into contiguous `(start, end)` runs, with `end` exclusive.
+fn column_runs(columns: &[i32]) -> Vec<(i32, i32)> {
+    let mut runs: Vec<(i32, i32)> = Vec::new();
+
+    for &x in columns {
+        match runs.last_mut() {
+            Some(run) if run.1 == x => run.1 = x + 1,
+            _ => runs.push((x, x + 1)),
+        }
+    }
+
+    runs
+}
+
+/// Components of a static wall collider covering `cells`, positioned in layer space.
+///
+/// Cell entities of an IntGrid layer sit at their tile center, so the layer origin is the center of cell `(0, 0)`.
+pub fn wall_collider(cells: IRect, grid_size: f32) -> impl Bundle {
+    let size = cells.size().as_vec2() * grid_size;
+    let center = cells.min.as_vec2() * grid_size + size / 2. - grid_size / 2.;
+
+    (
+        WallCollider { cells },
+        Transform::from_translation(center.extend(0.)),
+        Collider::rectangle(size.x, size.y),
+        world_collision_layers(),
+        CollidingEntities::default(),
+        RigidBody::Static,
+        Friction::new(0.),
+    )
+}
+
+/// Rebuilds the merged colliders of every IntGrid layer that got new [`Wall`] cells, such as when a level (re)loads.
+pub(crate) fn wall_collider_system(
+    mut commands: Commands,
+    added_walls: Query<&Parent, Added<Wall>>,
+    walls: Query<(&GridCoords, &Parent), With<Wall>>,
+    layers: Query<(&LayerMetadata, Option<&Children>)>,
+    colliders: Query<(), With<WallCollider>>,
+) {
+    let changed_layers: HashSet<Entity> = added_walls.iter().map(|parent| parent.get()).collect();
+
+    if changed_layers.is_empty() {
+        return;
+    }
+
+    let mut layer_cells: HashMap<Entity, Vec<IVec2>> = HashMap::new();
+
+    for (coords, parent) in walls.iter() {
+        if changed_layers.contains(&parent.get()) {
+            layer_cells
+                .entry(parent.get())
+                .or_default()
+                .push(IVec2::new(coords.x, coords.y));
+        }
+    }
+
+    for (layer, cells) in layer_cells {
+        let Ok((metadata, children)) = layers.get(layer) else {
+            continue;
+        };
+
+        for &child in children.into_iter().flatten() {
+            if colliders.contains(child) {
+                commands.entity(child).despawn_recursive();
+            }
+        }
+
+        let grid_size = metadata.grid_size as f32;
+
+        commands.entity(layer).with_children(|layer| {
+            for rect in merge_wall_cells(cells) {
+                layer.spawn(wall_collider(rect, grid_size));
+            }
+        });
+    }
+}
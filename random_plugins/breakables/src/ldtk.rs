@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+
+use crate::Breakable;
+
+/// IntGrid cell values that become [`Breakable`] tiles, with the hit points of each.
+///
+/// IntGrid layers drawn with auto-layer rules need a rule for these values, otherwise the cells are invisible.
+pub const LDTK_BREAKABLE_INT_GRID_VALUES: [(i32, i32); 3] = [(2, 1), (3, 2), (4, 3)];
+
+pub(crate) fn setup_ldtk_breakables(app: &mut App) {
+    for (value, _) in LDTK_BREAKABLE_INT_GRID_VALUES {
+        app.register_ldtk_int_cell::<BreakableCellBundle>(value);
+    }
+}
+
+#[derive(Clone, Debug, Default, Bundle, LdtkIntCell)]
+struct BreakableCellBundle {
+    #[with(breakable_from_value)]
+    breakable: Breakable,
+}
+
+fn breakable_from_value(int_grid_cell: IntGridCell) -> Breakable {
+    LDTK_BREAKABLE_INT_GRID_VALUES
+        .iter()
+        .find(|(value, _)| *value == int_grid_cell.value)
+        .map_or_else(Breakable::default, |(_, hit_points)| Breakable {
+            hit_points: *hit_points,
+        })
+}
@@ -0,0 +1,205 @@
+use std::f32::consts::PI;
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use physics_layers::world_collision_layers;
+
+#[cfg(feature = "ldtk")]
+pub mod ldtk;
+#[cfg(feature = "tiled")]
+pub mod tiled_map;
+
+pub const DEFAULT_HIT_POINTS: i32 = 3;
+
+const DEBRIS_COLOR: Color = Color::srgb(0.55, 0.45, 0.35);
+const DEBRIS_SIZE: f32 = 2.;
+const DEBRIS_SECONDS: f32 = 0.6;
+const DEBRIS_SPEED: f32 = 90.;
+const DEBRIS_GRAVITY: f32 = -400.;
+const HIT_DEBRIS_COUNT: usize = 3;
+const BREAK_DEBRIS_COUNT: usize = 10;
+
+/// Breaks [`Breakable`] tiles hit by [`BreakableHit`]s, sending a [`TileBroken`] for each.
+///
+/// Tiles can be marked breakable from Tiled with the `tiled` feature, or from LDtk with the `ldtk` feature.
+pub struct BreakablePlugin;
+
+impl Plugin for BreakablePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Breakable>()
+            .add_event::<BreakableHit>()
+            .add_event::<TileBroken>()
+            .add_systems(
+                Update,
+                (breakable_setup_system, breakable_hit_system, debris_system).chain(),
+            );
+
+        #[cfg(feature = "tiled")]
+        tiled_map::setup_tiled_breakables(app);
+
+        #[cfg(feature = "ldtk")]
+        ldtk::setup_ldtk_breakables(app);
+    }
+}
+
+/// A tilemap tile removed once it took `hit_points` damage.
+///
+/// Its solid collider covers the whole tile and is spawned as a child of the tilemap, so breakable tiles must be left
+/// out of any merged wall colliders.
+#[derive(Component, Reflect, Clone, Copy, Debug)]
+#[reflect(Component, Default)]
+pub struct Breakable {
+    pub hit_points: i32,
+}
+
+impl Default for Breakable {
+    fn default() -> Self {
+        Self {
+            hit_points: DEFAULT_HIT_POINTS,
+        }
+    }
+}
+
+/// Sent by games when an attack hits `target`, damaging it when it's a breakable tile or its collider.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct BreakableHit {
+    pub target: Entity,
+    pub damage: i32,
+}
+
+/// Sent once a breakable tile is removed, for games to drop pickups and the like.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TileBroken {
+    pub tilemap: Entity,
+    pub tile_pos: TilePos,
+    /// World position of the tile center.
+    pub position: Vec2,
+}
+
+/// Collider of a [`Breakable`] tile, spawned as a child of its tilemap.
+#[derive(Component, Debug)]
+struct BreakableCollider {
+    tile: Entity,
+}
+
+#[derive(Component)]
+struct Debris {
+    velocity: Vec2,
+    timer: Timer,
+}
+
+fn breakable_setup_system(
+    mut commands: Commands,
+    tiles: Query<(Entity, &TilePos, &TilemapId), Added<Breakable>>,
+    tilemaps: Query<(&TilemapGridSize, &TilemapType)>,
+) {
+    for (entity, tile_pos, tilemap_id) in tiles.iter() {
+        let Ok((grid_size, map_type)) = tilemaps.get(tilemap_id.0) else {
+            continue;
+        };
+
+        let center = tile_pos.center_in_world(grid_size, map_type);
+
+        commands.entity(tilemap_id.0).with_children(|parent| {
+            parent.spawn((
+                BreakableCollider { tile: entity },
+                Transform::from_translation(center.extend(0.)),
+                Collider::rectangle(grid_size.x, grid_size.y),
+                world_collision_layers(),
+                RigidBody::Static,
+                Friction::new(0.),
+            ));
+        });
+    }
+}
+
+fn breakable_hit_system(
+    mut commands: Commands,
+    mut hit_events: EventReader<BreakableHit>,
+    mut broken_events: EventWriter<TileBroken>,
+    colliders: Query<(Entity, &BreakableCollider, &GlobalTransform)>,
+    mut tiles: Query<(&mut Breakable, &TilePos, &TilemapId)>,
+    mut storages: Query<&mut TileStorage>,
+) {
+    for hit in hit_events.read() {
+        // Hits can target the tile itself, or its collider found by physics queries.
+        let Some((collider, tile, transform)) =
+            colliders.iter().find(|(collider, breakable, _)| {
+                *collider == hit.target || breakable.tile == hit.target
+            })
+        else {
+            continue;
+        };
+
+        let Ok((mut breakable, tile_pos, tilemap_id)) = tiles.get_mut(tile.tile) else {
+            continue;
+        };
+
+        // Already broken by an earlier hit this frame.
+        if breakable.hit_points <= 0 {
+            continue;
+        }
+
+        breakable.hit_points -= hit.damage;
+
+        // In front of the tilemap.
+        let position = transform.translation() + Vec3::Z;
+
+        if breakable.hit_points > 0 {
+            spawn_debris(&mut commands, position, HIT_DEBRIS_COUNT);
+            continue;
+        }
+
+        if let Ok(mut storage) = storages.get_mut(tilemap_id.0) {
+            storage.remove(tile_pos);
+        }
+
+        commands.entity(tile.tile).despawn_recursive();
+        commands.entity(collider).despawn_recursive();
+
+        spawn_debris(&mut commands, position, BREAK_DEBRIS_COUNT);
+
+        broken_events.send(TileBroken {
+            tilemap: tilemap_id.0,
+            tile_pos: *tile_pos,
+            position: position.truncate(),
+        });
+    }
+}
+
+/// Throws `count` pieces upwards, spread evenly over a half circle.
+fn spawn_debris(commands: &mut Commands, position: Vec3, count: usize) {
+    for index in 0..count {
+        let angle = PI * (index as f32 + 0.5) / count as f32;
+        // Alternate speeds so the pieces don't move as a single arc.
+        let speed = DEBRIS_SPEED * if index % 2 == 0 { 1. } else { 0.6 };
+
+        commands.spawn((
+            Debris {
+                velocity: Vec2::from_angle(angle) * speed,
+                timer: Timer::from_seconds(DEBRIS_SECONDS, TimerMode::Once),
+            },
+            Sprite::from_color(DEBRIS_COLOR, Vec2::splat(DEBRIS_SIZE)),
+            Transform::from_translation(position),
+        ));
+    }
+}
+
+fn debris_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut debris: Query<(Entity, &mut Debris, &mut Transform, &mut Sprite)>,
+) {
+    for (entity, mut piece, mut transform, mut sprite) in debris.iter_mut() {
+        piece.timer.tick(time.delta());
+        piece.velocity.y += DEBRIS_GRAVITY * time.delta_secs();
+
+        transform.translation += (piece.velocity * time.delta_secs()).extend(0.);
+        sprite.color.set_alpha(1. - piece.timer.fraction());
+
+        if piece.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use bevy_ecs_tiled::prelude::*;
+
+use crate::Breakable;
+
+/// Tiles with this int property become [`Breakable`] with that many hit points.
+pub const TILED_HIT_POINTS_PROPERTY: &str = "hit_points";
+
+pub(crate) fn setup_tiled_breakables(app: &mut App) {
+    app.add_observer(tiled_breakable_observer);
+}
+
+/// Hit points of a breakable tile, `None` for other tiles.
+pub fn tile_hit_points(tile: &tiled::TileData) -> Option<i32> {
+    match tile.properties.get(TILED_HIT_POINTS_PROPERTY) {
+        Some(tiled::PropertyValue::IntValue(hit_points)) => Some(*hit_points),
+        _ => None,
+    }
+}
+
+/// Whether the collider comes from the collision shapes of a breakable tile, whose collider is spawned by the
+/// [`crate::BreakablePlugin`] instead.
+pub fn is_breakable_tile_collider(map: &tiled::Map, collider_source: &TiledColliderSource) -> bool {
+    collider_source
+        .tile(map)
+        .is_some_and(|tile| tile_hit_points(&tile).is_some())
+}
+
+fn tiled_breakable_observer(
+    trigger: Trigger<TiledSpecialTileCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+) {
+    let event = trigger.event();
+
+    let Some(hit_points) = event
+        .tile(&maps)
+        .get_tile()
+        .and_then(|tile| tile_hit_points(&tile))
+    else {
+        return;
+    };
+
+    commands.entity(event.tile).insert(Breakable { hit_points });
+}
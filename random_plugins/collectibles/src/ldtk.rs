@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+
+use crate::{Collectible, CollectibleKind};
+
+/// LDtk entities named `Collectible` become collectibles.
+///
+/// The optional `kind` enum field (`Coin`, `Key` or `Health`) and `value` int field override the defaults.
+pub const LDTK_COLLECTIBLE_IDENTIFIER: &str = "Collectible";
+
+pub(crate) fn setup_ldtk_collectibles(app: &mut App) {
+    app.register_ldtk_entity::<CollectibleEntityBundle>(LDTK_COLLECTIBLE_IDENTIFIER);
+}
+
+#[derive(Bundle, LdtkEntity, Default)]
+struct CollectibleEntityBundle {
+    #[with(collectible_from_fields)]
+    collectible: Collectible,
+}
+
+fn collectible_from_fields(entity_instance: &EntityInstance) -> Collectible {
+    let default = Collectible::default();
+
+    let kind = match entity_instance.get_enum_field("kind").map(String::as_str) {
+        Ok("Key") => CollectibleKind::Key,
+        Ok("Health") => CollectibleKind::Health,
+        Ok("Coin") | Err(_) => default.kind,
+        Ok(other) => {
+            warn!("Unknown collectible kind {other}, defaulting to {:?}", default.kind);
+            default.kind
+        }
+    };
+
+    let value = entity_instance
+        .get_int_field("value")
+        .copied()
+        .unwrap_or(default.value);
+
+    Collectible { kind, value }
+}
@@ -0,0 +1,197 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use physics_layers::{collectible_collision_layers, is_player};
+
+#[cfg(feature = "ldtk")]
+pub mod ldtk;
+
+const PICKUP_ANIMATION_SECONDS: f32 = 0.3;
+const PICKUP_ANIMATION_RISE: f32 = 12.;
+const COLLECTIBLE_RADIUS: f32 = 4.;
+
+pub struct CollectiblePlugin;
+
+impl Plugin for CollectiblePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Collectible>()
+            .register_type::<CollectibleKind>()
+            .init_resource::<Inventory>()
+            .add_event::<Collected>()
+            .add_systems(
+                Update,
+                (
+                    collectible_setup_system,
+                    collectible_pickup_system,
+                    pickup_animation_system,
+                ),
+            );
+
+        #[cfg(feature = "ldtk")]
+        ldtk::setup_ldtk_collectibles(app);
+    }
+}
+
+#[derive(Reflect, Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[reflect(Default)]
+pub enum CollectibleKind {
+    #[default]
+    Coin,
+    Key,
+    Health,
+}
+
+/// A pickup the player can collect by touching it.
+///
+/// Can be placed directly from Tiled through `user_properties`, or from LDtk with the `ldtk` feature.
+#[derive(Component, Reflect, Clone, Copy, Debug)]
+#[reflect(Component, Default)]
+pub struct Collectible {
+    pub kind: CollectibleKind,
+    pub value: i32,
+}
+
+impl Default for Collectible {
+    fn default() -> Self {
+        Self {
+            kind: CollectibleKind::Coin,
+            value: 1,
+        }
+    }
+}
+
+/// Sprites used for each collectible kind. Kinds without an image are left invisible.
+#[derive(Resource, Default)]
+pub struct CollectibleSprites(pub HashMap<CollectibleKind, Handle<Image>>);
+
+#[derive(Resource, Default, Debug)]
+pub struct Inventory {
+    pub score: i32,
+    pub counts: HashMap<CollectibleKind, i32>,
+}
+
+impl Inventory {
+    pub fn count(&self, kind: CollectibleKind) -> i32 {
+        self.counts.get(&kind).copied().unwrap_or(0)
+    }
+
+    pub fn take(&mut self, kind: CollectibleKind) -> bool {
+        match self.counts.get_mut(&kind) {
+            Some(count) if *count > 0 => {
+                *count -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Event, Debug)]
+pub struct Collected {
+    pub player: Entity,
+    pub collectible: Entity,
+    pub kind: CollectibleKind,
+    pub value: i32,
+}
+
+#[derive(Component)]
+struct PickupAnimation {
+    timer: Timer,
+    start: Vec3,
+}
+
+fn collectible_setup_system(
+    mut commands: Commands,
+    sprites: Option<Res<CollectibleSprites>>,
+    collectibles: Query<(Entity, &Collectible), Added<Collectible>>,
+) {
+    for (entity, collectible) in collectibles.iter() {
+        commands.entity(entity).insert((
+            Collider::circle(COLLECTIBLE_RADIUS),
+            Sensor,
+            collectible_collision_layers(),
+        ));
+
+        let image = sprites
+            .as_ref()
+            .and_then(|sprites| sprites.0.get(&collectible.kind));
+
+        if let Some(image) = image {
+            commands.entity(entity).insert(Sprite {
+                image: image.clone(),
+                ..default()
+            });
+        }
+    }
+}
+
+fn collectible_pickup_system(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionStarted>,
+    mut inventory: ResMut<Inventory>,
+    mut collected_events: EventWriter<Collected>,
+    collectibles: Query<(&Collectible, &Transform), Without<PickupAnimation>>,
+    layers: Query<&CollisionLayers>,
+) {
+    for CollisionStarted(a, b) in collision_events.read() {
+        let (collectible_entity, player) = if collectibles.contains(*a) {
+            (*a, *b)
+        } else if collectibles.contains(*b) {
+            (*b, *a)
+        } else {
+            continue;
+        };
+
+        if !layers.get(player).is_ok_and(is_player) {
+            continue;
+        }
+
+        let Ok((collectible, transform)) = collectibles.get(collectible_entity) else {
+            continue;
+        };
+
+        if collectible.kind == CollectibleKind::Coin {
+            inventory.score += collectible.value;
+        }
+
+        *inventory.counts.entry(collectible.kind).or_default() += collectible.value;
+
+        collected_events.send(Collected {
+            player,
+            collectible: collectible_entity,
+            kind: collectible.kind,
+            value: collectible.value,
+        });
+
+        commands
+            .entity(collectible_entity)
+            .remove::<(Collider, Sensor)>()
+            .insert(PickupAnimation {
+                timer: Timer::from_seconds(PICKUP_ANIMATION_SECONDS, TimerMode::Once),
+                start: transform.translation,
+            });
+    }
+}
+
+fn pickup_animation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut animations: Query<(Entity, &mut PickupAnimation, &mut Transform, Option<&mut Sprite>)>,
+) {
+    for (entity, mut animation, mut transform, sprite) in animations.iter_mut() {
+        animation.timer.tick(time.delta());
+
+        let t = animation.timer.fraction();
+
+        transform.translation = animation.start + Vec3::Y * PICKUP_ANIMATION_RISE * t * (2. - t);
+        transform.scale = Vec3::splat(1. + t * 0.5);
+
+        if let Some(mut sprite) = sprite {
+            sprite.color.set_alpha(1. - t);
+        }
+
+        if animation.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use crate::player_const_rules::WALL_HIT_KICKBACK_ACCELERATION;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum AttackDirection {
+    #[default]
+    Forward,
+    Up,
+    Down,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum AttackKind {
+    /// Forward attack, carrying its position in the current combo chain (1-based).
+    Combo(u8),
+    Up,
+    /// Downward attack while airborne, bouncing the player off enemies and hazards.
+    Pogo,
+}
+
+/// Per attack tuning: which animation to play, where the hitbox is and how hard the player is pushed back.
+///
+/// `hitbox_offset` and `kickback` are expressed for a player facing right, and are mirrored when facing left.
+#[derive(Clone, Copy, Debug)]
+pub struct AttackProfile {
+    pub animation_row: usize,
+    pub animation_frames: usize,
+    pub frame_seconds: f32,
+    pub hitbox_offset: Vec2,
+    pub hitbox_size: Vec2,
+    pub kickback: Vec2,
+    pub hit_stop_seconds: f32,
+}
+
+impl AttackKind {
+    pub fn profile(&self) -> AttackProfile {
+        match self {
+            AttackKind::Combo(1) => AttackProfile {
+                animation_row: 4,
+                animation_frames: 4,
+                frame_seconds: 0.020,
+                hitbox_offset: Vec2::new(12., 0.),
+                hitbox_size: Vec2::new(16., 20.),
+                kickback: Vec2::new(-0.4, 0.7).normalize() * WALL_HIT_KICKBACK_ACCELERATION,
+                hit_stop_seconds: 0.04,
+            },
+            AttackKind::Combo(2) => AttackProfile {
+                animation_row: 5,
+                animation_frames: 3,
+                frame_seconds: 0.025,
+                hitbox_offset: Vec2::new(14., 0.),
+                hitbox_size: Vec2::new(20., 20.),
+                kickback: Vec2::new(-0.4, 0.7).normalize() * WALL_HIT_KICKBACK_ACCELERATION,
+                hit_stop_seconds: 0.05,
+            },
+            AttackKind::Combo(_) => AttackProfile {
+                animation_row: 6,
+                animation_frames: 4,
+                frame_seconds: 0.035,
+                hitbox_offset: Vec2::new(16., 2.),
+                hitbox_size: Vec2::new(24., 24.),
+                kickback: Vec2::new(-0.6, 0.8).normalize() * WALL_HIT_KICKBACK_ACCELERATION * 1.25,
+                hit_stop_seconds: 0.08,
+            },
+            AttackKind::Up => AttackProfile {
+                animation_row: 7,
+                animation_frames: 3,
+                frame_seconds: 0.025,
+                hitbox_offset: Vec2::new(0., 20.),
+                hitbox_size: Vec2::new(20., 16.),
+                kickback: Vec2::new(0., -WALL_HIT_KICKBACK_ACCELERATION * 0.5),
+                hit_stop_seconds: 0.05,
+            },
+            AttackKind::Pogo => AttackProfile {
+                animation_row: 8,
+                animation_frames: 3,
+                frame_seconds: 0.025,
+                hitbox_offset: Vec2::new(0., -20.),
+                hitbox_size: Vec2::new(16., 16.),
+                kickback: Vec2::ZERO,
+                hit_stop_seconds: 0.05,
+            },
+        }
+    }
+}
+
+#[derive(Event, Debug)]
+pub struct AttackHit {
+    pub attacker: Entity,
+    pub target: Entity,
+    pub kind: AttackKind,
+    pub hit_world: bool,
+}
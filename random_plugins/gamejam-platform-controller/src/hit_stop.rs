@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+
+/// Freezes or slows down the game for a short while, e.g. when an attack connects.
+///
+/// `duration` is measured in real seconds, `scale` is the relative speed of virtual time
+/// while the hit stop is active (`0.` is a full freeze frame). Physics follows virtual time,
+/// so it is slowed down as well.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct HitStop {
+    pub duration: f32,
+    pub scale: f32,
+}
+
+impl HitStop {
+    pub fn freeze(duration: f32) -> Self {
+        Self {
+            duration,
+            scale: 0.,
+        }
+    }
+
+    pub fn slow_motion(duration: f32, scale: f32) -> Self {
+        Self { duration, scale }
+    }
+}
+
+#[derive(Resource)]
+pub struct HitStopSettings {
+    pub enabled: bool,
+    pub ease_out_seconds: f32,
+}
+
+impl Default for HitStopSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ease_out_seconds: 0.1,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct ActiveHitStop {
+    remaining: f32,
+    scale: f32,
+    ease_elapsed: Option<f32>,
+    /// Relative speed of virtual time before the hit stop, eased back to afterwards.
+    restore_speed: f32,
+}
+
+pub struct HitStopPlugin;
+
+impl Plugin for HitStopPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HitStop>()
+            .init_resource::<HitStopSettings>()
+            .init_resource::<ActiveHitStop>()
+            .add_systems(PreUpdate, hit_stop_system);
+    }
+}
+
+fn hit_stop_system(
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    settings: Res<HitStopSettings>,
+    mut active: ResMut<ActiveHitStop>,
+    mut hit_stop_events: EventReader<HitStop>,
+) {
+    for event in hit_stop_events.read() {
+        if !settings.enabled {
+            continue;
+        }
+
+        let is_active = active.remaining > 0.;
+
+        if !is_active && active.ease_elapsed.is_none() {
+            active.restore_speed = virtual_time.relative_speed();
+        }
+
+        active.scale = if is_active {
+            active.scale.min(event.scale)
+        } else {
+            event.scale
+        }
+        .max(0.);
+        active.remaining = active.remaining.max(event.duration);
+        active.ease_elapsed = None;
+    }
+
+    let delta = real_time.delta_secs();
+
+    if active.remaining > 0. {
+        active.remaining -= delta;
+        virtual_time.set_relative_speed(active.scale);
+
+        if active.remaining <= 0. {
+            active.ease_elapsed = Some(0.);
+        }
+
+        return;
+    }
+
+    let Some(ease_elapsed) = active.ease_elapsed else {
+        return;
+    };
+
+    let ease_elapsed = ease_elapsed + delta;
+    let t = (ease_elapsed / settings.ease_out_seconds.max(f32::EPSILON)).min(1.);
+    let eased = t * (2. - t);
+
+    virtual_time.set_relative_speed(active.scale + (active.restore_speed - active.scale) * eased);
+
+    active.ease_elapsed = if t < 1. { Some(ease_elapsed) } else { None };
+}
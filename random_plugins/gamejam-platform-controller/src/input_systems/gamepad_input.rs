@@ -1,3 +1,4 @@
+use crate::attacks::AttackDirection;
 use crate::MovementAction;
 use bevy::prelude::*;
 
@@ -11,7 +12,19 @@ pub fn gamepad_input_system(
     };
 
     if gamepad.just_pressed(GamepadButton::West) {
-        event_sender.send(MovementAction::Attack);
+        let attack_direction = if gamepad.pressed(GamepadButton::DPadUp) {
+            AttackDirection::Up
+        } else if gamepad.pressed(GamepadButton::DPadDown) {
+            AttackDirection::Down
+        } else {
+            AttackDirection::Forward
+        };
+
+        event_sender.send(MovementAction::Attack(attack_direction));
+    }
+
+    if gamepad.just_pressed(GamepadButton::North) {
+        event_sender.send(MovementAction::Shoot);
     }
 
     if gamepad.pressed(GamepadButton::DPadRight) {
@@ -1,6 +1,7 @@
 use bevy::prelude::{EventWriter, KeyCode, Res};
 use bevy::input::ButtonInput;
 use bevy::math::Vec2;
+use crate::attacks::AttackDirection;
 use crate::MovementAction;
 
 pub fn keyboard_input_system(
@@ -9,8 +10,20 @@ pub fn keyboard_input_system(
 ) {
     let mut direction = Vec2::ZERO;
 
-    if key_input.pressed(KeyCode::KeyF) {
-        event_sender.send(MovementAction::Attack);
+    if key_input.just_pressed(KeyCode::KeyF) {
+        let attack_direction = if key_input.pressed(KeyCode::KeyW) || key_input.pressed(KeyCode::ArrowUp) {
+            AttackDirection::Up
+        } else if key_input.pressed(KeyCode::KeyS) || key_input.pressed(KeyCode::ArrowDown) {
+            AttackDirection::Down
+        } else {
+            AttackDirection::Forward
+        };
+
+        event_sender.send(MovementAction::Attack(attack_direction));
+    }
+
+    if key_input.just_pressed(KeyCode::KeyG) {
+        event_sender.send(MovementAction::Shoot);
     }
 
     if key_input.pressed(KeyCode::KeyD) || key_input.pressed(KeyCode::ArrowRight) {
@@ -1,17 +1,28 @@
+use crate::attacks::{AttackDirection, AttackHit};
+use crate::hit_stop::HitStopPlugin;
 use crate::input_systems::gamepad_input::gamepad_input_system;
 use crate::input_systems::keyboard_input_system::keyboard_input_system;
 use crate::player_systems::grounded_system::grounded_system;
 use crate::player_systems::movement_dampening_system::movement_dampening_system;
+use crate::player_systems::player_attack_system::player_attack_system;
 use crate::player_systems::player_control_system::player_control_system;
+use crate::projectile_components::{ProjectileImpact, SpawnProjectile};
+use crate::projectile_systems::projectile_emitter_system::projectile_emitter_system;
+use crate::projectile_systems::projectile_movement_system::projectile_movement_system;
+use crate::projectile_systems::projectile_spawn_system::projectile_spawn_system;
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
 use bevy_ecs_ldtk::prelude::*;
 use player_systems::player_spawn_system;
 
+pub mod attacks;
+pub mod hit_stop;
 mod input_systems;
 pub mod player_components;
 mod player_const_rules;
 pub mod player_systems;
+pub mod projectile_components;
+pub mod projectile_systems;
 
 #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
 pub enum GameStates {
@@ -25,7 +36,8 @@ pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.init_state::<GameStates>()
+        app.add_plugins(HitStopPlugin)
+            .init_state::<GameStates>()
             .add_loading_state(
                 LoadingState::new(GameStates::Loading)
                     .continue_to_state(GameStates::SpawnPlayer)
@@ -36,6 +48,9 @@ impl Plugin for PlayerPlugin {
                 player_spawn_system::spawn_player_system,
             )
             .add_event::<MovementAction>()
+            .add_event::<AttackHit>()
+            .add_event::<SpawnProjectile>()
+            .add_event::<ProjectileImpact>()
             .add_systems(Update, player_spawn_system::update_player_spawn)
             .add_systems(
                 Update,
@@ -44,11 +59,23 @@ impl Plugin for PlayerPlugin {
                     keyboard_input_system,
                     gamepad_input_system,
                     player_control_system,
+                    player_attack_system,
                     movement_dampening_system,
                     animate_sprite_system,
                 )
                     .run_if(in_state(GameStates::GameLoop))
                     .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    projectile_emitter_system,
+                    projectile_spawn_system,
+                    projectile_movement_system,
+                )
+                    .after(player_control_system)
+                    .run_if(in_state(GameStates::GameLoop))
+                    .chain(),
             );
 
         setup_ldtk_entities(app);
@@ -66,7 +93,7 @@ pub struct PlayerSpawnSettings {
 
 #[derive(AssetCollection, Resource)]
 pub struct PlayerAssets {
-    #[asset(texture_atlas_layout(tile_size_x = 32, tile_size_y = 32, columns = 4, rows = 5))]
+    #[asset(texture_atlas_layout(tile_size_x = 32, tile_size_y = 32, columns = 4, rows = 9))]
     player_layout: Handle<TextureAtlasLayout>,
     #[asset(image(sampler(filter = nearest)))]
     #[asset(path = "sprites/guy.png")]
@@ -77,6 +104,7 @@ pub struct PlayerAssets {
 struct PlayerAnimation {
     timer: Timer,
     animation_row: usize,
+    animation_frames: usize,
     animation_count: usize,
 }
 
@@ -94,7 +122,7 @@ fn animate_sprite_system(time: Res<Time>, mut query: Query<(&mut PlayerAnimation
 
         if timer.timer.finished() {
             if let Some(atlas) = &mut sprite.texture_atlas {
-                timer.animation_count = (timer.animation_count + 1) % 4;
+                timer.animation_count = (timer.animation_count + 1) % timer.animation_frames;
                 atlas.index = timer.animation_row * 4 + timer.animation_count;
             }
         }
@@ -106,5 +134,6 @@ pub enum MovementAction {
     Horizontal(Vec2),
     Jump,
     JumpAbort,
-    Attack,
+    Attack(AttackDirection),
+    Shoot,
 }
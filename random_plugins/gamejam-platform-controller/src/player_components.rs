@@ -1,7 +1,9 @@
 use avian2d::prelude::*;
 use bevy::prelude::*;
+use physics_layers::{player_collision_layers, world_query_filter};
 use simple_2d_camera::PixelCameraTracked;
 
+use crate::attacks::AttackKind;
 use crate::player_const_rules::*;
 
 #[derive(Component)]
@@ -10,10 +12,13 @@ use crate::player_const_rules::*;
     RigidBody(|| RigidBody::Dynamic),
     Collider(|| Collider::rectangle(7., 30.)),
     CollisionMargin(|| CollisionMargin::from(COLLISION_MARGIN)),
-    CollisionLayers(|| CollisionLayers::new(0b00001, 0b00101)),
+    CollisionLayers(player_collision_layers),
     ExternalForce(|| ExternalForce::default().with_persistence(false)),
     GravityScale,
-    ShapeCaster(|| ShapeCaster::new(Collider::rectangle(4., 4.), Vec2::ZERO, 0., Dir2::NEG_Y)),
+    ShapeCaster(|| {
+        ShapeCaster::new(Collider::rectangle(4., 4.), Vec2::ZERO, 0., Dir2::NEG_Y)
+            .with_query_filter(world_query_filter())
+    }),
     LockedAxes(|| LockedAxes::ROTATION_LOCKED),
     MovementDampeningFactor(|| MovementDampeningFactor(X_DAMPENING_FACTOR)),
     JumpState,
@@ -32,11 +37,14 @@ pub struct Moving;
 #[derive(Component)]
 pub struct Attacking {
     pub attack_started_at: f64,
+    pub kind: AttackKind,
 }
 
 #[derive(Component, Default)]
 pub struct PlayerActionTracker {
     pub last_attack_at: Option<f64>,
+    pub last_shot_at: Option<f64>,
+    pub combo_step: u8,
 }
 
 #[derive(Component, Default)]
@@ -1,3 +1,5 @@
+use bevy::math::Vec2;
+
 pub const COLLISION_MARGIN: f32 = 1.;
 pub const TILE_SIZE_PIXELS: f32 = 16.;
 pub const MAX_SPEED: f32 = TILE_SIZE_PIXELS * 20.;
@@ -8,4 +10,11 @@ pub const JUMP_SPEED: f32 = 350.;
 pub const X_DAMPENING_FACTOR: f32 = 15.;
 pub const FALL_GRAVITY: f32 = 12.0;
 pub const PLAYER_ATTACK_DELAY_SECONDS: f64 = 0.15;
-pub const WALL_HIT_KICKBACK_ACCELERATION: f32 = 800.;
\ No newline at end of file
+pub const WALL_HIT_KICKBACK_ACCELERATION: f32 = 800.;
+pub const ATTACK_COMBO_WINDOW_SECONDS: f64 = 0.45;
+pub const MAX_ATTACK_COMBO_STEPS: u8 = 3;
+pub const POGO_BOUNCE_SPEED: f32 = JUMP_SPEED * 1.1;
+pub const PLAYER_SHOOT_DELAY_SECONDS: f64 = 0.3;
+pub const PLAYER_PROJECTILE_SPEED: f32 = TILE_SIZE_PIXELS * 25.;
+pub const PLAYER_PROJECTILE_LIFETIME_SECONDS: f32 = 1.;
+pub const PROJECTILE_SIZE: Vec2 = Vec2::new(6., 2.);
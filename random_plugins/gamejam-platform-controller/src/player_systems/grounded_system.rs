@@ -1,10 +1,10 @@
 use avian2d::parry::na::inf;
-use bevy::prelude::{Camera, Commands, Entity, Query, Res, Time, Transform, With};
-use avian2d::prelude::{LinearVelocity, ShapeHits, SpatialQuery, SpatialQueryFilter};
+use bevy::prelude::{Commands, Entity, Query, Res, Time, Transform, With};
+use avian2d::prelude::{LinearVelocity, ShapeHits, SpatialQuery};
 use bevy::log::info;
 use bevy::math::Dir2;
-use bevy_trauma_shake::Shake;
-use simple_2d_camera::CameraShake;
+use physics_layers::world_query_filter;
+use simple_2d_camera::{CameraShakeEvent, PixelCameraTracked};
 use crate::PlayerAnimation;
 use crate::player_components::{Attacking, Grounded, JumpState, Player};
 use crate::player_const_rules::MAX_Y_SPEED;
@@ -21,14 +21,22 @@ pub fn grounded_system(
             &mut PlayerAnimation,
             &Transform,
             Option<&Attacking>,
+            &mut PixelCameraTracked,
         ),
         With<Player>,
     >,
-    mut camera_query: Query<&mut Shake, With<Camera>>,
     spatial_query: SpatialQuery,
 ) {
-    for (entity, hits, mut jump_state_data, velocity, mut animation, player_transform, attacking) in
-        &mut query
+    for (
+        entity,
+        hits,
+        mut jump_state_data,
+        velocity,
+        mut animation,
+        player_transform,
+        attacking,
+        mut camera_tracked,
+    ) in &mut query
     {
         let is_grounded = hits.iter().any(|hit| {
             hit.point2.y < 0.
@@ -48,14 +56,13 @@ pub fn grounded_system(
 
             if velocity.y >= 0. {
                 commands.entity(entity).insert(Grounded);
+                camera_tracked.grounded = true;
                 jump_state_data.used = 0;
                 jump_state_data.left_ground_at = None;
             }
 
             if velocity.y <= -MAX_Y_SPEED * 2. / 3. {
-                if let Ok(mut camera_entity) = camera_query.get_single_mut() {
-                    camera_entity.add_trauma(0.3);
-                }
+                commands.send_event(CameraShakeEvent::new(0.3, 0.35).with_direction(Dir2::Y));
             }
         } else {
             // Check for collisions when going up
@@ -66,7 +73,7 @@ pub fn grounded_system(
                     50.,
                     2,
                     true,
-                    &SpatialQueryFilter::default(),
+                    &world_query_filter(),
                 );
 
                 if up_hits.iter().any(|hit| hit.distance < 18.) {
@@ -75,6 +82,7 @@ pub fn grounded_system(
             }
 
             commands.entity(entity).remove::<Grounded>();
+            camera_tracked.grounded = false;
             if attacking.is_none() {
                 animation.animation_row = 3;
             }
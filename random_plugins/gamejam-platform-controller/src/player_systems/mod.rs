@@ -1,4 +1,5 @@
 pub mod movement_dampening_system;
 pub mod grounded_system;
 pub mod player_spawn_system;
-pub mod player_control_system;
\ No newline at end of file
+pub mod player_control_system;
+pub mod player_attack_system;
\ No newline at end of file
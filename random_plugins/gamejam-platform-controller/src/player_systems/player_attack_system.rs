@@ -0,0 +1,181 @@
+use crate::attacks::{AttackDirection, AttackHit, AttackKind};
+use crate::hit_stop::HitStop;
+use crate::player_components::{Attacking, Grounded, JumpState, Player, PlayerActionTracker};
+use crate::player_const_rules::{
+    ATTACK_COMBO_WINDOW_SECONDS, MAX_ATTACK_COMBO_STEPS, PLAYER_ATTACK_DELAY_SECONDS,
+    POGO_BOUNCE_SPEED,
+};
+use crate::{MovementAction, PlayerAnimation};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use physics_layers::GameLayer;
+use simple_2d_camera::CameraShakeEvent;
+
+type AttackingPlayerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut LinearVelocity,
+        Option<&'static Grounded>,
+        &'static mut JumpState,
+        &'static mut PlayerAnimation,
+        &'static mut Sprite,
+        Option<&'static Attacking>,
+        &'static mut PlayerActionTracker,
+        &'static Transform,
+    ),
+    With<Player>,
+>;
+
+pub(crate) fn player_attack_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut movement_events: EventReader<MovementAction>,
+    mut player_query: AttackingPlayerQuery,
+    layers_query: Query<&CollisionLayers>,
+    spatial_query: SpatialQuery,
+) {
+    let Some(direction) = movement_events
+        .read()
+        .filter_map(|action| match action {
+            MovementAction::Attack(direction) => Some(*direction),
+            _ => None,
+        })
+        .last()
+    else {
+        return;
+    };
+
+    for (
+        entity,
+        mut linear_velocity,
+        grounded,
+        mut jump_state,
+        mut animation,
+        mut sprite,
+        attacking,
+        mut player_actions,
+        player_transform,
+    ) in player_query.iter_mut()
+    {
+        if attacking.is_some() {
+            continue;
+        }
+
+        let now = time.elapsed_secs_f64();
+        let last_attack_at = player_actions.last_attack_at.unwrap_or(0.);
+
+        if now - last_attack_at < PLAYER_ATTACK_DELAY_SECONDS {
+            continue;
+        }
+
+        let kind = match direction {
+            AttackDirection::Up => AttackKind::Up,
+            AttackDirection::Down if grounded.is_none() => AttackKind::Pogo,
+            _ => {
+                let in_combo_window = now - last_attack_at <= ATTACK_COMBO_WINDOW_SECONDS;
+
+                player_actions.combo_step =
+                    if in_combo_window && player_actions.combo_step < MAX_ATTACK_COMBO_STEPS {
+                        player_actions.combo_step + 1
+                    } else {
+                        1
+                    };
+
+                AttackKind::Combo(player_actions.combo_step)
+            }
+        };
+
+        if !matches!(kind, AttackKind::Combo(_)) {
+            player_actions.combo_step = 0;
+        }
+
+        player_actions.last_attack_at = Some(now);
+
+        let profile = kind.profile();
+
+        animation.animation_row = profile.animation_row;
+        animation.animation_frames = profile.animation_frames;
+        animation.animation_count = 0;
+        animation.timer = Timer::from_seconds(profile.frame_seconds, TimerMode::Repeating);
+
+        commands.entity(entity).insert(Attacking {
+            attack_started_at: now,
+            kind,
+        });
+
+        let facing = if sprite.flip_x { -1. } else { 1. };
+        let mirror = Vec2::new(facing, 1.);
+        let hitbox_center = player_transform.translation.truncate() + profile.hitbox_offset * mirror;
+
+        let hits = spatial_query.shape_intersections(
+            &Collider::rectangle(profile.hitbox_size.x, profile.hitbox_size.y),
+            hitbox_center,
+            0.,
+            &SpatialQueryFilter::from_mask([
+                GameLayer::World,
+                GameLayer::Enemy,
+                GameLayer::Hazard,
+                GameLayer::Interactable,
+            ])
+            .with_excluded_entities([entity]),
+        );
+
+        let mut hit_world = false;
+        let mut hit_target = false;
+
+        for target in hits {
+            let is_member = |layer: GameLayer| {
+                layers_query
+                    .get(target)
+                    .is_ok_and(|layers| layers.memberships.has_all(layer))
+            };
+            let is_world = is_member(GameLayer::World);
+            // Interactables such as switches react to the hit, but don't push the player back or bounce them.
+            let is_interactable = is_member(GameLayer::Interactable);
+
+            hit_world |= is_world;
+            hit_target |= !is_world && !is_interactable;
+
+            commands.send_event(AttackHit {
+                attacker: entity,
+                target,
+                kind,
+                hit_world: is_world,
+            });
+        }
+
+        if kind == AttackKind::Pogo {
+            if !hit_target {
+                continue;
+            }
+
+            linear_velocity.y = POGO_BOUNCE_SPEED;
+            jump_state.used = 0;
+            jump_state.left_ground_at = None;
+        } else {
+            if !hit_world && !hit_target {
+                continue;
+            }
+
+            let kickback = profile.kickback * mirror;
+
+            linear_velocity.x += kickback.x;
+            linear_velocity.y += kickback.y;
+
+            if kickback.x != 0. {
+                sprite.flip_x = linear_velocity.x < 0.;
+            }
+        }
+
+        let shake_direction = match kind {
+            AttackKind::Combo(_) => Dir2::X,
+            AttackKind::Up | AttackKind::Pogo => Dir2::Y,
+        };
+
+        commands.send_event(CameraShakeEvent::soft().with_direction(shake_direction));
+
+        commands.send_event(HitStop::freeze(profile.hit_stop_seconds));
+    }
+}
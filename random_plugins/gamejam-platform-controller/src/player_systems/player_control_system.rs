@@ -1,13 +1,12 @@
 use crate::player_components::{
     Attacking, Grounded, JumpState, Moving, Player, PlayerActionTracker,
 };
-use crate::player_const_rules::{ACCELERATION, FALL_GRAVITY, JUMP_SPEED, MAX_JUMP_ACCELERATION_TIME, MAX_SPEED, MAX_Y_SPEED, PLAYER_ATTACK_DELAY_SECONDS, WALL_HIT_KICKBACK_ACCELERATION};
+use crate::player_const_rules::{ACCELERATION, FALL_GRAVITY, JUMP_SPEED, MAX_JUMP_ACCELERATION_TIME, MAX_SPEED, MAX_Y_SPEED, PLAYER_PROJECTILE_LIFETIME_SECONDS, PLAYER_PROJECTILE_SPEED, PLAYER_SHOOT_DELAY_SECONDS};
+use crate::projectile_components::{Projectile, SpawnProjectile};
 use crate::{MovementAction, PlayerAnimation};
 use avian2d::math::AdjustPrecision;
 use avian2d::prelude::*;
 use bevy::prelude::*;
-use bevy_trauma_shake::Shake;
-use simple_2d_camera::CameraShake;
 
 pub fn player_control_system(
     mut commands: Commands,
@@ -28,8 +27,7 @@ pub fn player_control_system(
         ),
         With<Player>,
     >,
-    mut camera_query: Query<&mut Shake, With<Camera>>,
-    spatial_query: SpatialQuery,
+    mut projectile_events: EventWriter<SpawnProjectile>,
 ) {
     let delta_t = time.delta_secs_f64().adjust_precision();
 
@@ -57,9 +55,10 @@ pub fn player_control_system(
         }
 
         if let Some(_attacking) = attacking {
-            if animation.animation_count == 3 {
+            if animation.animation_count == animation.animation_frames - 1 {
                 animation.timer = Timer::from_seconds(0.1, TimerMode::Repeating);
                 animation.animation_row = 0;
+                animation.animation_frames = 4;
                 commands.entity(entity).remove::<Attacking>();
             }
 
@@ -122,48 +121,30 @@ pub fn player_control_system(
                         }
                     }
                 }
-                MovementAction::Attack => {
+                MovementAction::Attack(_) => {
+                    // Attacks are resolved by `player_attack_system`
+                }
+                MovementAction::Shoot => {
                     let now = time.elapsed_secs_f64();
 
-                    if now - player_actions.last_attack_at.unwrap_or(0.)
-                        < PLAYER_ATTACK_DELAY_SECONDS
-                    {
+                    if now - player_actions.last_shot_at.unwrap_or(0.) < PLAYER_SHOOT_DELAY_SECONDS {
                         continue;
                     }
 
-                    player_actions.last_attack_at = Some(now);
+                    player_actions.last_shot_at = Some(now);
 
-                    animation.animation_row = 4;
-                    animation.animation_count = 0;
-                    animation.timer = Timer::from_seconds(0.020, TimerMode::Repeating);
+                    let facing_direction = if sprite.flip_x { Dir2::NEG_X } else { Dir2::X };
 
-                    commands.entity(entity).insert(Attacking {
-                        attack_started_at: now,
+                    projectile_events.send(SpawnProjectile {
+                        origin: player_transform.translation.truncate(),
+                        direction: facing_direction,
+                        projectile: Projectile::new(
+                            PLAYER_PROJECTILE_SPEED,
+                            PLAYER_PROJECTILE_LIFETIME_SECONDS,
+                        ),
+                        owner: Some(entity),
+                        color: Color::WHITE,
                     });
-
-                    let facing_direction = if sprite.flip_x { -1. } else { 1. };
-
-                    // Process hits
-                    let hits = spatial_query.ray_hits(
-                        player_transform.translation.truncate(),
-                        Dir2::from_xy(1. * facing_direction, 0.).unwrap(),
-                        10.,
-                        2,
-                        true,
-                        &SpatialQueryFilter::default()
-                    );
-
-                    if hits.len() > 1 {
-                        let kickback = Vec2::new(facing_direction * -0.4, 0.7).normalize();
-
-                        linear_velocity.x += kickback.x * WALL_HIT_KICKBACK_ACCELERATION;
-                        linear_velocity.y += kickback.y * WALL_HIT_KICKBACK_ACCELERATION;
-                        sprite.flip_x = linear_velocity.x < 0.;
-
-                        if let Ok(mut camera_entity) = camera_query.get_single_mut() {
-                            camera_entity.add_trauma(0.2);
-                        }
-                    }
                 }
             }
         }
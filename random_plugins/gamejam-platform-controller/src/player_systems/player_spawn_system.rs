@@ -22,6 +22,7 @@ pub fn spawn_player_system(
         PlayerAnimation {
             timer: Timer::from_seconds(0.1, TimerMode::Repeating),
             animation_row: 0,
+            animation_frames: 4,
             animation_count: 0,
         },
     ));
@@ -0,0 +1,80 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use physics_layers::player_projectile_collision_layers;
+
+#[derive(Component, Clone)]
+#[require(Transform, Sprite)]
+pub struct Projectile {
+    pub speed: f32,
+    pub velocity: Vec2,
+    pub lifetime: Timer,
+    pub gravity: f32,
+    pub pierce: u8,
+    pub layers: CollisionLayers,
+    pub owner: Option<Entity>,
+    pub hit_entities: Vec<Entity>,
+}
+
+impl Projectile {
+    pub fn new(speed: f32, lifetime_seconds: f32) -> Self {
+        Self {
+            speed,
+            velocity: Vec2::ZERO,
+            lifetime: Timer::from_seconds(lifetime_seconds, TimerMode::Once),
+            gravity: 0.,
+            pierce: 0,
+            layers: player_projectile_collision_layers(),
+            owner: None,
+            hit_entities: vec![],
+        }
+    }
+
+    pub fn with_gravity(mut self, gravity: f32) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    pub fn with_pierce(mut self, pierce: u8) -> Self {
+        self.pierce = pierce;
+        self
+    }
+
+    pub fn with_layers(mut self, layers: CollisionLayers) -> Self {
+        self.layers = layers;
+        self
+    }
+}
+
+/// Periodically fires projectiles from the entity it is attached to, e.g. an enemy turret.
+#[derive(Component)]
+pub struct ProjectileEmitter {
+    pub timer: Timer,
+    pub direction: Dir2,
+    pub projectile: Projectile,
+    pub color: Color,
+}
+
+#[derive(Event)]
+pub struct SpawnProjectile {
+    pub origin: Vec2,
+    pub direction: Dir2,
+    pub projectile: Projectile,
+    pub owner: Option<Entity>,
+    pub color: Color,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum ProjectileImpactKind {
+    Wall,
+    Target,
+}
+
+#[derive(Event, Debug)]
+pub struct ProjectileImpact {
+    pub projectile: Entity,
+    pub owner: Option<Entity>,
+    pub target: Entity,
+    pub point: Vec2,
+    pub normal: Vec2,
+    pub kind: ProjectileImpactKind,
+}
@@ -0,0 +1,3 @@
+pub mod projectile_emitter_system;
+pub mod projectile_movement_system;
+pub mod projectile_spawn_system;
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use crate::projectile_components::{ProjectileEmitter, SpawnProjectile};
+
+pub fn projectile_emitter_system(
+    time: Res<Time>,
+    mut spawn_events: EventWriter<SpawnProjectile>,
+    mut emitters: Query<(Entity, &mut ProjectileEmitter, &Transform)>,
+) {
+    for (entity, mut emitter, transform) in emitters.iter_mut() {
+        emitter.timer.tick(time.delta());
+
+        if !emitter.timer.just_finished() {
+            continue;
+        }
+
+        spawn_events.send(SpawnProjectile {
+            origin: transform.translation.truncate(),
+            direction: emitter.direction,
+            projectile: emitter.projectile.clone(),
+            owner: Some(entity),
+            color: emitter.color,
+        });
+    }
+}
@@ -0,0 +1,85 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use crate::projectile_components::{Projectile, ProjectileImpact, ProjectileImpactKind};
+
+pub fn projectile_movement_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    gravity: Res<Gravity>,
+    mut projectiles: Query<(Entity, &mut Projectile, &mut Transform)>,
+    rigid_bodies: Query<&RigidBody>,
+    spatial_query: SpatialQuery,
+    mut impact_events: EventWriter<ProjectileImpact>,
+) {
+    let delta_t = time.delta_secs();
+
+    for (entity, mut projectile, mut transform) in projectiles.iter_mut() {
+        projectile.lifetime.tick(time.delta());
+
+        if projectile.lifetime.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let acceleration = gravity.0 * projectile.gravity;
+        projectile.velocity += acceleration * delta_t;
+
+        let origin = transform.translation.truncate();
+        let displacement = projectile.velocity * delta_t;
+
+        let Ok(direction) = Dir2::new(displacement) else {
+            continue;
+        };
+
+        let filter = SpatialQueryFilter::from_mask(projectile.layers.filters)
+            .with_excluded_entities(projectile.owner.iter().chain(projectile.hit_entities.iter()).copied());
+
+        let mut hits = spatial_query.ray_hits(
+            origin,
+            direction,
+            displacement.length(),
+            projectile.pierce as u32 + 2,
+            true,
+            &filter,
+        );
+
+        hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+        let mut despawned = false;
+
+        for hit in hits {
+            let is_wall = rigid_bodies
+                .get(hit.entity)
+                .is_ok_and(|body| body.is_static());
+
+            impact_events.send(ProjectileImpact {
+                projectile: entity,
+                owner: projectile.owner,
+                target: hit.entity,
+                point: origin + *direction * hit.distance,
+                normal: hit.normal,
+                kind: if is_wall {
+                    ProjectileImpactKind::Wall
+                } else {
+                    ProjectileImpactKind::Target
+                },
+            });
+
+            if is_wall || projectile.pierce == 0 {
+                despawned = true;
+                break;
+            }
+
+            projectile.pierce -= 1;
+            projectile.hit_entities.push(hit.entity);
+        }
+
+        if despawned {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        transform.translation += displacement.extend(0.);
+        transform.rotation = Quat::from_rotation_z(direction.to_angle());
+    }
+}
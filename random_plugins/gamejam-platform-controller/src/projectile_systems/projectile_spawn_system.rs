@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+use crate::player_const_rules::PROJECTILE_SIZE;
+use crate::projectile_components::SpawnProjectile;
+
+pub fn projectile_spawn_system(
+    mut commands: Commands,
+    mut spawn_events: EventReader<SpawnProjectile>,
+) {
+    for event in spawn_events.read() {
+        let mut projectile = event.projectile.clone();
+        projectile.velocity = event.direction * projectile.speed;
+        projectile.owner = event.owner;
+
+        commands.spawn((
+            Transform::from_xyz(event.origin.x, event.origin.y, 0.6)
+                .with_rotation(Quat::from_rotation_z(event.direction.to_angle())),
+            Sprite {
+                color: event.color,
+                custom_size: Some(PROJECTILE_SIZE),
+                ..default()
+            },
+            projectile,
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use collectibles::{CollectibleKind, Inventory};
+use physics_layers::{is_player, world_collision_layers};
+
+use crate::Switch;
+
+const DOOR_COLOR: Color = Color::srgb(0.45, 0.3, 0.2);
+const LOCKED_DOOR_COLOR: Color = Color::srgb(0.8, 0.65, 0.2);
+/// Time for a door to slide fully open or closed.
+const DOOR_SLIDE_SECONDS: f32 = 0.4;
+
+/// A solid door covering `area`, open while every linked switch is on and it isn't locked.
+///
+/// A locked door is unlocked by the player touching it with a key collectible, which is used up. A door without
+/// switches is only closed while locked. Its collider is spawned as a child covering the whole area, with a sprite
+/// sliding up when opening.
+#[derive(Component, Clone, Debug)]
+pub struct Door {
+    /// Area relative to the entity position.
+    pub area: Rect,
+    pub switches: Vec<Entity>,
+    pub locked: bool,
+    open: bool,
+}
+
+impl Door {
+    pub fn new(area: Rect) -> Self {
+        Self {
+            area,
+            switches: Vec::new(),
+            locked: false,
+            open: false,
+        }
+    }
+
+    pub fn with_switches(mut self, switches: Vec<Entity>) -> Self {
+        self.switches = switches;
+        self
+    }
+
+    pub fn locked(mut self) -> Self {
+        self.locked = true;
+        self
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Missing switches, such as despawned ones, count as off.
+    fn should_open(&self, switches: &Query<&Switch>) -> bool {
+        !self.locked
+            && self
+                .switches
+                .iter()
+                .all(|switch| switches.get(*switch).is_ok_and(|switch| switch.on))
+    }
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DoorChanged {
+    pub door: Entity,
+    pub open: bool,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DoorUnlocked {
+    pub door: Entity,
+    pub player: Entity,
+}
+
+/// Collider of a [`Door`], spawned as its child. The sliding sprite is a child of the panel, so the collider stays put.
+#[derive(Component, Debug)]
+pub(crate) struct DoorPanel {
+    door: Entity,
+    size: Vec2,
+    /// From 0 when closed to 1 when fully open.
+    openness: f32,
+}
+
+pub(crate) fn door_setup_system(
+    mut commands: Commands,
+    mut doors: Query<(Entity, &mut Door), Added<Door>>,
+    switches: Query<&Switch>,
+) {
+    for (entity, mut door) in doors.iter_mut() {
+        // Doors start in their current state instead of animating to it.
+        door.open = door.should_open(&switches);
+
+        let size = door.area.size();
+        let center = door.area.center();
+
+        commands.entity(entity).with_children(|parent| {
+            let mut panel = parent.spawn((
+                DoorPanel {
+                    door: entity,
+                    size,
+                    openness: if door.open { 1. } else { 0. },
+                },
+                Transform::from_translation(center.extend(0.)),
+                Visibility::default(),
+                RigidBody::Static,
+                Collider::rectangle(size.x, size.y),
+                world_collision_layers(),
+                Friction::new(0.),
+            ));
+
+            panel.with_children(|panel| {
+                panel.spawn((Sprite::from_color(door_color(&door), size), Transform::default()));
+            });
+
+            if door.open {
+                panel.insert(ColliderDisabled);
+            }
+        });
+    }
+}
+
+pub(crate) fn door_unlock_system(
+    mut collision_events: EventReader<CollisionStarted>,
+    mut unlocked_events: EventWriter<DoorUnlocked>,
+    mut inventory: ResMut<Inventory>,
+    mut doors: Query<&mut Door>,
+    panels: Query<&DoorPanel>,
+    layers: Query<&CollisionLayers>,
+) {
+    let is_player = |entity: Entity| layers.get(entity).is_ok_and(is_player);
+
+    for CollisionStarted(a, b) in collision_events.read() {
+        let (panel, player) = match (panels.get(*a), panels.get(*b)) {
+            (Ok(panel), _) if is_player(*b) => (panel, *b),
+            (_, Ok(panel)) if is_player(*a) => (panel, *a),
+            _ => continue,
+        };
+
+        let Ok(mut door) = doors.get_mut(panel.door) else {
+            continue;
+        };
+
+        if door.locked && inventory.take(CollectibleKind::Key) {
+            door.locked = false;
+
+            unlocked_events.send(DoorUnlocked {
+                door: panel.door,
+                player,
+            });
+        }
+    }
+}
+
+pub(crate) fn door_state_system(
+    mut commands: Commands,
+    mut changed_events: EventWriter<DoorChanged>,
+    mut doors: Query<(Entity, &mut Door)>,
+    switches: Query<&Switch>,
+    panels: Query<(Entity, &DoorPanel)>,
+) {
+    for (entity, mut door) in doors.iter_mut() {
+        let open = door.should_open(&switches);
+
+        if open == door.open {
+            continue;
+        }
+
+        door.open = open;
+
+        changed_events.send(DoorChanged { door: entity, open });
+
+        for (panel_entity, _) in panels.iter().filter(|(_, panel)| panel.door == entity) {
+            // Closing doors are solid right away, opening ones let the player through before they're fully open.
+            if open {
+                commands.entity(panel_entity).insert(ColliderDisabled);
+            } else {
+                commands.entity(panel_entity).remove::<ColliderDisabled>();
+            }
+        }
+    }
+}
+
+pub(crate) fn door_animation_system(
+    time: Res<Time>,
+    doors: Query<&Door>,
+    mut panels: Query<(&mut DoorPanel, &Children)>,
+    mut sprites: Query<(&mut Transform, &mut Sprite)>,
+) {
+    let step = time.delta_secs() / DOOR_SLIDE_SECONDS;
+
+    for (mut panel, children) in panels.iter_mut() {
+        let Ok(door) = doors.get(panel.door) else {
+            continue;
+        };
+
+        let target = if door.open { 1. } else { 0. };
+        panel.openness += (target - panel.openness).clamp(-step, step);
+
+        let mut sprites = sprites.iter_many_mut(children);
+
+        while let Some((mut transform, mut sprite)) = sprites.fetch_next() {
+            // Slides up into the top of the door area.
+            sprite.custom_size = Some(Vec2::new(
+                panel.size.x,
+                panel.size.y * (1. - panel.openness),
+            ));
+            sprite.color = door_color(door);
+            transform.translation.y = panel.size.y * panel.openness / 2.;
+        }
+    }
+}
+
+fn door_color(door: &Door) -> Color {
+    if door.locked {
+        LOCKED_DOOR_COLOR
+    } else {
+        DOOR_COLOR
+    }
+}
@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+
+use crate::{Door, Switch, SwitchActivation};
+
+/// LDtk entities named `Switch` become [`Switch`]es covering the entity.
+///
+/// The optional `on` bool field turns it on from the start, and the optional `activation` enum field (`Any`, `Contact`
+/// or `Attack`) picks what toggles it.
+pub const LDTK_SWITCH_IDENTIFIER: &str = "Switch";
+
+/// LDtk entities named `Door` become [`Door`]s covering the entity.
+///
+/// The optional `switches` entity reference array field links the door to those switches, and the optional `locked`
+/// bool field requires a key to open it.
+pub const LDTK_DOOR_IDENTIFIER: &str = "Door";
+
+pub(crate) fn setup_ldtk_mechanics(app: &mut App) {
+    app.register_ldtk_entity::<SwitchBundle>(LDTK_SWITCH_IDENTIFIER);
+    app.register_ldtk_entity::<DoorLinksBundle>(LDTK_DOOR_IDENTIFIER);
+    app.add_systems(
+        Update,
+        (ldtk_mechanic_scale_system, ldtk_door_links_system)
+            .chain()
+            .before(crate::switches::switch_setup_system),
+    );
+}
+
+type AddedLdtkMechanic = (
+    Or<(Added<Switch>, Added<LdtkDoorLinks>)>,
+    With<EntityInstance>,
+);
+
+/// bevy_ecs_ldtk scales resized entities by their size over their definition size, which would scale the switch and
+/// door areas a second time since they're already the entity size.
+fn ldtk_mechanic_scale_system(mut mechanics: Query<&mut Transform, AddedLdtkMechanic>) {
+    for mut transform in mechanics.iter_mut() {
+        transform.scale = Vec3::ONE;
+    }
+}
+
+#[derive(Bundle, LdtkEntity)]
+struct SwitchBundle {
+    #[with(switch_from_fields)]
+    switch: Switch,
+}
+
+fn switch_from_fields(entity_instance: &EntityInstance) -> Switch {
+    let activation = match entity_instance.get_enum_field("activation") {
+        Ok(name) => SwitchActivation::from_name(name).unwrap_or_else(|| {
+            warn!(
+                "Unknown switch activation {name}, defaulting to {:?}",
+                SwitchActivation::default()
+            );
+            SwitchActivation::default()
+        }),
+        Err(_) => SwitchActivation::default(),
+    };
+
+    let size = Vec2::new(entity_instance.width as f32, entity_instance.height as f32);
+    let switch = Switch::new(Rect::from_center_size(Vec2::ZERO, size)).with_activation(activation);
+
+    match entity_instance.get_bool_field("on") {
+        Ok(true) => switch.turned_on(),
+        _ => switch,
+    }
+}
+
+/// A door waiting for the entities of its linked switches.
+#[derive(Component)]
+struct LdtkDoorLinks {
+    door: Door,
+    switches: Vec<String>,
+}
+
+#[derive(Bundle, LdtkEntity)]
+struct DoorLinksBundle {
+    #[with(door_links_from_fields)]
+    links: LdtkDoorLinks,
+}
+
+fn door_links_from_fields(entity_instance: &EntityInstance) -> LdtkDoorLinks {
+    let size = Vec2::new(entity_instance.width as f32, entity_instance.height as f32);
+    let door = Door::new(Rect::from_center_size(Vec2::ZERO, size));
+
+    let door = match entity_instance.get_bool_field("locked") {
+        Ok(true) => door.locked(),
+        _ => door,
+    };
+
+    let switches = entity_instance
+        .iter_entity_refs_field("switches")
+        .map(|references| {
+            references
+                .map(|reference| reference.entity_iid.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    LdtkDoorLinks { door, switches }
+}
+
+/// Switches can spawn after the doors referencing them, so doors are linked once their whole level is spawned.
+///
+/// Switches that can't be found, such as ones in levels that aren't loaded, count as off and keep the door closed.
+fn ldtk_door_links_system(
+    mut commands: Commands,
+    doors: Query<(Entity, &LdtkDoorLinks)>,
+    switches: Query<(Entity, &EntityIid), With<Switch>>,
+) {
+    for (entity, links) in doors.iter() {
+        let switch_entities = links
+            .switches
+            .iter()
+            .map(|iid| {
+                switches
+                    .iter()
+                    .find(|(_, switch_iid)| switch_iid.as_str() == iid)
+                    .map(|(switch, _)| switch)
+                    .unwrap_or_else(|| {
+                        warn!("Door switch {iid} not found, the door stays closed");
+                        Entity::PLACEHOLDER
+                    })
+            })
+            .collect();
+
+        commands
+            .entity(entity)
+            .remove::<LdtkDoorLinks>()
+            .insert(links.door.clone().with_switches(switch_entities));
+    }
+}
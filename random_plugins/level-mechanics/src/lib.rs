@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+
+mod doors;
+#[cfg(feature = "ldtk")]
+pub mod ldtk;
+mod switches;
+#[cfg(feature = "tiled")]
+pub mod tiled_map;
+
+pub use doors::{Door, DoorChanged, DoorUnlocked};
+pub use switches::{Switch, SwitchActivation, SwitchHit, SwitchToggled, SWITCH_TRIGGER_ID};
+
+/// Switches, and doors opened by linked switches or by a key collectible.
+///
+/// Needs the `CollectiblePlugin` for locked doors, keys are taken from its `Inventory`, and the `TriggerPlugin` for
+/// switch contacts. Switches and doors can be
+/// placed from Tiled with the `tiled` feature, or from LDtk with the `ldtk` feature.
+pub struct LevelMechanicsPlugin;
+
+impl Plugin for LevelMechanicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SwitchActivation>()
+            .add_event::<SwitchHit>()
+            .add_event::<SwitchToggled>()
+            .add_event::<DoorChanged>()
+            .add_event::<DoorUnlocked>()
+            .add_systems(
+                Update,
+                (
+                    switches::switch_setup_system,
+                    doors::door_setup_system,
+                    switches::switch_contact_system,
+                    switches::switch_hit_system,
+                    doors::door_unlock_system,
+                    doors::door_state_system,
+                    switches::switch_animation_system,
+                    doors::door_animation_system,
+                )
+                    .chain(),
+            );
+
+        #[cfg(feature = "tiled")]
+        tiled_map::setup_tiled_mechanics(app);
+
+        #[cfg(feature = "ldtk")]
+        ldtk::setup_ldtk_mechanics(app);
+    }
+}
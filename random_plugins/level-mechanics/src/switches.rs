@@ -0,0 +1,232 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use physics_layers::interactable_collision_layers;
+use triggers::{Trigger, TriggerEvent, TriggerKind};
+
+const LEVER_SIZE: Vec2 = Vec2::new(3., 12.);
+/// Lever tilt from vertical, in radians, leaning left when off and right when on.
+const LEVER_ANGLE: f32 = 0.5;
+/// Lever rotation speed, in radians per second.
+const LEVER_TURN_SPEED: f32 = 8.;
+const SWITCH_OFF_COLOR: Color = Color::srgb(0.75, 0.25, 0.2);
+const SWITCH_ON_COLOR: Color = Color::srgb(0.3, 0.75, 0.3);
+
+/// Id of the [`TriggerEvent`]s sent by switches, toggling them on contact.
+pub const SWITCH_TRIGGER_ID: &str = "switch";
+
+/// What can toggle a [`Switch`].
+#[derive(Reflect, Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[reflect(Default)]
+pub enum SwitchActivation {
+    /// The player touching it, or an attack hitting it.
+    #[default]
+    Any,
+    /// Only the player touching it.
+    Contact,
+    /// Only an attack hitting it, see [`SwitchHit`].
+    Attack,
+}
+
+impl SwitchActivation {
+    /// Parses the variant name, as written in map properties.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Any" => Some(SwitchActivation::Any),
+            "Contact" => Some(SwitchActivation::Contact),
+            "Attack" => Some(SwitchActivation::Attack),
+            _ => None,
+        }
+    }
+
+    fn by_contact(self) -> bool {
+        matches!(self, SwitchActivation::Any | SwitchActivation::Contact)
+    }
+
+    fn by_attack(self) -> bool {
+        matches!(self, SwitchActivation::Any | SwitchActivation::Attack)
+    }
+}
+
+/// A lever toggled on and off by the player, opening the [`crate::Door`]s linked to it.
+///
+/// Contact is detected by a [`Trigger`] added over `area`, while its attack sensor and lever sprite are spawned as
+/// children. The lever stands at the bottom of `area`.
+#[derive(Component, Clone, Debug)]
+pub struct Switch {
+    pub on: bool,
+    pub activation: SwitchActivation,
+    /// Area relative to the entity position.
+    pub area: Rect,
+}
+
+impl Switch {
+    pub fn new(area: Rect) -> Self {
+        Self {
+            on: false,
+            activation: SwitchActivation::default(),
+            area,
+        }
+    }
+
+    pub fn with_activation(mut self, activation: SwitchActivation) -> Self {
+        self.activation = activation;
+        self
+    }
+
+    pub fn turned_on(mut self) -> Self {
+        self.on = true;
+        self
+    }
+
+    fn toggle(&mut self, entity: Entity, toggled_events: &mut EventWriter<SwitchToggled>) {
+        self.on = !self.on;
+
+        toggled_events.send(SwitchToggled {
+            switch: entity,
+            on: self.on,
+        });
+    }
+}
+
+/// Sent by games when an attack hits `target`, toggling it when it's a switch activated by attacks.
+///
+/// Switch colliders are on the `Interactable` layer, attacks need to include it in their hit checks.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SwitchHit {
+    pub target: Entity,
+}
+
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SwitchToggled {
+    pub switch: Entity,
+    pub on: bool,
+}
+
+/// Sensor collider of a [`Switch`] targeted by attacks, spawned as its child.
+#[derive(Component, Debug)]
+pub(crate) struct SwitchSensor {
+    switch: Entity,
+}
+
+/// Lever sprite of a [`Switch`], spawned as its child.
+#[derive(Component, Debug)]
+pub(crate) struct SwitchLever {
+    switch: Entity,
+}
+
+pub(crate) fn switch_setup_system(
+    mut commands: Commands,
+    switches: Query<(Entity, &Switch), Added<Switch>>,
+) {
+    for (entity, switch) in switches.iter() {
+        let size = switch.area.size();
+        let lever_base = Vec2::new(switch.area.center().x, switch.area.min.y);
+
+        commands
+            .entity(entity)
+            .insert(Trigger::new(
+                SWITCH_TRIGGER_ID,
+                TriggerKind::Enter,
+                switch.area,
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    SwitchSensor { switch: entity },
+                    Transform::from_translation(switch.area.center().extend(0.)),
+                    Collider::rectangle(size.x, size.y),
+                    Sensor,
+                    interactable_collision_layers(),
+                ));
+
+                parent.spawn((
+                    SwitchLever { switch: entity },
+                    Sprite {
+                        color: switch_color(switch),
+                        custom_size: Some(LEVER_SIZE),
+                        anchor: Anchor::BottomCenter,
+                        ..default()
+                    },
+                    Transform::from_translation(lever_base.extend(0.))
+                        .with_rotation(Quat::from_rotation_z(lever_angle(switch))),
+                ));
+            });
+    }
+}
+
+pub(crate) fn switch_contact_system(
+    mut trigger_events: EventReader<TriggerEvent>,
+    mut toggled_events: EventWriter<SwitchToggled>,
+    mut switches: Query<&mut Switch>,
+) {
+    for event in trigger_events.read() {
+        if event.id != SWITCH_TRIGGER_ID {
+            continue;
+        }
+
+        let Ok(mut switch) = switches.get_mut(event.trigger) else {
+            continue;
+        };
+
+        if switch.activation.by_contact() {
+            switch.toggle(event.trigger, &mut toggled_events);
+        }
+    }
+}
+
+pub(crate) fn switch_hit_system(
+    mut hit_events: EventReader<SwitchHit>,
+    mut toggled_events: EventWriter<SwitchToggled>,
+    mut switches: Query<&mut Switch>,
+    sensors: Query<&SwitchSensor>,
+) {
+    for hit in hit_events.read() {
+        let Ok(sensor) = sensors.get(hit.target) else {
+            continue;
+        };
+
+        let Ok(mut switch) = switches.get_mut(sensor.switch) else {
+            continue;
+        };
+
+        if switch.activation.by_attack() {
+            switch.toggle(sensor.switch, &mut toggled_events);
+        }
+    }
+}
+
+pub(crate) fn switch_animation_system(
+    time: Res<Time>,
+    switches: Query<&Switch>,
+    mut levers: Query<(&SwitchLever, &mut Transform, &mut Sprite)>,
+) {
+    let step = LEVER_TURN_SPEED * time.delta_secs();
+
+    for (lever, mut transform, mut sprite) in levers.iter_mut() {
+        let Ok(switch) = switches.get(lever.switch) else {
+            continue;
+        };
+
+        let (_, _, angle) = transform.rotation.to_euler(EulerRot::XYZ);
+        let angle = angle + (lever_angle(switch) - angle).clamp(-step, step);
+
+        transform.rotation = Quat::from_rotation_z(angle);
+        sprite.color = switch_color(switch);
+    }
+}
+
+fn lever_angle(switch: &Switch) -> f32 {
+    if switch.on {
+        -LEVER_ANGLE
+    } else {
+        LEVER_ANGLE
+    }
+}
+
+fn switch_color(switch: &Switch) -> Color {
+    if switch.on {
+        SWITCH_ON_COLOR
+    } else {
+        SWITCH_OFF_COLOR
+    }
+}
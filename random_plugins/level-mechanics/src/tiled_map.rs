@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+use bevy_ecs_tiled::prelude::*;
+
+use crate::{Door, Switch, SwitchActivation};
+
+/// Tiled rectangle objects with this class become [`Switch`]es.
+///
+/// The optional `on` bool property turns it on from the start, and the optional `activation` string property (`Any`,
+/// `Contact` or `Attack`) picks what toggles it.
+pub const TILED_SWITCH_CLASS: &str = "Switch";
+
+/// Tiled rectangle objects with this class become [`Door`]s.
+///
+/// Object properties whose name starts with `switch` (`switch`, `switch 2`...) link the door to those switches, and
+/// the optional `locked` bool property requires a key to open it.
+pub const TILED_DOOR_CLASS: &str = "Door";
+
+pub(crate) fn setup_tiled_mechanics(app: &mut App) {
+    app.add_observer(tiled_switch_observer);
+    app.add_observer(tiled_door_observer);
+    app.add_systems(
+        Update,
+        tiled_door_links_system.before(crate::doors::door_setup_system),
+    );
+}
+
+/// Whether the collider comes from a switch or door object, whose colliders are spawned by the
+/// [`crate::LevelMechanicsPlugin`] instead.
+pub fn is_mechanic_collider(map: &tiled::Map, collider_source: &TiledColliderSource) -> bool {
+    collider_source.object(map).is_some_and(|object| {
+        object.user_type == TILED_SWITCH_CLASS || object.user_type == TILED_DOOR_CLASS
+    })
+}
+
+/// A door waiting for the entities of its linked switch objects.
+#[derive(Component)]
+struct TiledDoorLinks {
+    map: Entity,
+    door: Door,
+    switches: Vec<u32>,
+}
+
+fn tiled_switch_observer(
+    trigger: Trigger<TiledObjectCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+) {
+    let event = trigger.event();
+    let object = event.object(&maps);
+
+    if object.user_type != TILED_SWITCH_CLASS {
+        return;
+    }
+
+    let tiled::ObjectShape::Rect { width, height } = object.shape else {
+        warn!("Switch {} must be a rectangle", object.name);
+        return;
+    };
+
+    let activation = match object.properties.get("activation") {
+        Some(tiled::PropertyValue::StringValue(name)) => SwitchActivation::from_name(name)
+            .unwrap_or_else(|| {
+                warn!(
+                    "Unknown switch activation {name}, defaulting to {:?}",
+                    SwitchActivation::default()
+                );
+                SwitchActivation::default()
+            }),
+        _ => SwitchActivation::default(),
+    };
+
+    // Object positions are their top left corner.
+    let area = Rect::from_corners(Vec2::ZERO, Vec2::new(width, -height));
+    let switch = Switch::new(area).with_activation(activation);
+
+    let switch = match object.properties.get("on") {
+        Some(tiled::PropertyValue::BoolValue(true)) => switch.turned_on(),
+        _ => switch,
+    };
+
+    commands.entity(event.object).insert(switch);
+}
+
+fn tiled_door_observer(
+    trigger: Trigger<TiledObjectCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+) {
+    let event = trigger.event();
+    let object = event.object(&maps);
+
+    if object.user_type != TILED_DOOR_CLASS {
+        return;
+    }
+
+    let tiled::ObjectShape::Rect { width, height } = object.shape else {
+        warn!("Door {} must be a rectangle", object.name);
+        return;
+    };
+
+    // Object positions are their top left corner.
+    let area = Rect::from_corners(Vec2::ZERO, Vec2::new(width, -height));
+    let door = Door::new(area);
+
+    let door = match object.properties.get("locked") {
+        Some(tiled::PropertyValue::BoolValue(true)) => door.locked(),
+        _ => door,
+    };
+
+    // Unset object properties are 0.
+    let switches = object
+        .properties
+        .iter()
+        .filter(|(name, _)| name.starts_with("switch"))
+        .filter_map(|(_, value)| match value {
+            tiled::PropertyValue::ObjectValue(id) if *id != 0 => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    commands.entity(event.object).insert(TiledDoorLinks {
+        map: event.map,
+        door,
+        switches,
+    });
+}
+
+/// Object entities are only known once the whole map is spawned, so doors are linked afterwards.
+///
+/// Linked objects that don't exist or aren't switches count as off and keep the door closed.
+fn tiled_door_links_system(
+    mut commands: Commands,
+    doors: Query<(Entity, &TiledDoorLinks)>,
+    maps: Query<&TiledIdStorage>,
+    switches: Query<(), With<Switch>>,
+) {
+    for (entity, links) in doors.iter() {
+        let Ok(storage) = maps.get(links.map) else {
+            continue;
+        };
+
+        let switch_entities = links
+            .switches
+            .iter()
+            .map(|id| match storage.objects.get(id) {
+                Some(switch) if switches.contains(*switch) => *switch,
+                _ => {
+                    warn!("Door switch object {id} is not a switch, the door stays closed");
+                    Entity::PLACEHOLDER
+                }
+            })
+            .collect();
+
+        commands
+            .entity(entity)
+            .remove::<TiledDoorLinks>()
+            .insert(links.door.clone().with_switches(switch_entities));
+    }
+}
@@ -0,0 +1,113 @@
+use avian2d::prelude::*;
+
+/// Physics layers shared by all katas and plugins.
+///
+/// `Default` is kept as the first layer so colliders that never had layers assigned
+/// don't accidentally end up as players or world geometry.
+#[derive(PhysicsLayer, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GameLayer {
+    #[default]
+    Default,
+    Player,
+    World,
+    Collectible,
+    Enemy,
+    Hazard,
+    Sensor,
+    OneWay,
+    /// Switches and other level mechanics the player can touch or attack.
+    Interactable,
+    /// Projectiles fired by the player, kept apart from `Player` so they're never mistaken for the player.
+    PlayerProjectile,
+}
+
+/// Layers considered solid ground for grounding and movement checks.
+pub const WORLD_GEOMETRY: [GameLayer; 2] = [GameLayer::World, GameLayer::OneWay];
+
+pub fn player_collision_layers() -> CollisionLayers {
+    CollisionLayers::new(
+        GameLayer::Player,
+        [
+            GameLayer::World,
+            GameLayer::OneWay,
+            GameLayer::Collectible,
+            GameLayer::Enemy,
+            GameLayer::Hazard,
+            GameLayer::Sensor,
+            GameLayer::Interactable,
+        ],
+    )
+}
+
+pub fn world_collision_layers() -> CollisionLayers {
+    CollisionLayers::new(
+        GameLayer::World,
+        [
+            GameLayer::Default,
+            GameLayer::Player,
+            GameLayer::Enemy,
+            GameLayer::PlayerProjectile,
+        ],
+    )
+}
+
+pub fn one_way_collision_layers() -> CollisionLayers {
+    CollisionLayers::new(GameLayer::OneWay, [GameLayer::Player, GameLayer::Enemy])
+}
+
+pub fn collectible_collision_layers() -> CollisionLayers {
+    CollisionLayers::new(GameLayer::Collectible, GameLayer::Player)
+}
+
+pub fn enemy_collision_layers() -> CollisionLayers {
+    CollisionLayers::new(
+        GameLayer::Enemy,
+        [
+            GameLayer::World,
+            GameLayer::OneWay,
+            GameLayer::Player,
+            GameLayer::Hazard,
+            GameLayer::PlayerProjectile,
+        ],
+    )
+}
+
+pub fn hazard_collision_layers() -> CollisionLayers {
+    CollisionLayers::new(
+        GameLayer::Hazard,
+        [
+            GameLayer::Player,
+            GameLayer::Enemy,
+            GameLayer::PlayerProjectile,
+        ],
+    )
+}
+
+pub fn sensor_collision_layers() -> CollisionLayers {
+    CollisionLayers::new(GameLayer::Sensor, GameLayer::Player)
+}
+
+pub fn interactable_collision_layers() -> CollisionLayers {
+    CollisionLayers::new(GameLayer::Interactable, GameLayer::Player)
+}
+
+pub fn player_projectile_collision_layers() -> CollisionLayers {
+    CollisionLayers::new(
+        GameLayer::PlayerProjectile,
+        [GameLayer::World, GameLayer::Enemy, GameLayer::Hazard],
+    )
+}
+
+pub fn enemy_projectile_collision_layers() -> CollisionLayers {
+    CollisionLayers::new(GameLayer::Enemy, [GameLayer::World, GameLayer::Player])
+}
+
+/// Whether the layers are those of a player, for telling players apart in collision events.
+pub fn is_player(layers: &CollisionLayers) -> bool {
+    layers.memberships.has_all(GameLayer::Player)
+}
+
+/// Spatial query filter that only reports solid world geometry.
+pub fn world_query_filter() -> SpatialQueryFilter {
+    SpatialQueryFilter::from_mask(WORLD_GEOMETRY)
+}
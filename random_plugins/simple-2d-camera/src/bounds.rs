@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+/// World-space rectangle the camera view is kept inside of. `None` lets the camera roam freely.
+///
+/// Filled automatically with the `tiled` feature from the map the tracked entities are in, and with the `ldtk` feature
+/// from the selected level. Can be set by hand otherwise.
+#[derive(Resource, Default, Debug)]
+pub struct CameraBounds(pub Option<Rect>);
+
+/// Clamps a camera position so the view stays inside `bounds`, centering on axes where the level is smaller than the view.
+pub fn clamp_to_bounds(position: Vec2, view_half_size: Vec2, bounds: Rect) -> Vec2 {
+    let clamp_axis = |position: f32, half_size: f32, min: f32, max: f32| {
+        if max - min <= half_size * 2. {
+            (min + max) / 2.
+        } else {
+            position.clamp(min + half_size, max - half_size)
+        }
+    };
+
+    Vec2::new(
+        clamp_axis(position.x, view_half_size.x, bounds.min.x, bounds.max.x),
+        clamp_axis(position.y, view_half_size.y, bounds.min.y, bounds.max.y),
+    )
+}
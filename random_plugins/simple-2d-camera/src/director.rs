@@ -0,0 +1,298 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::{CameraFollowState, CameraZoom, PixelCamera, PixelCameraTracked};
+
+/// One step of a [`CameraSequence`].
+#[derive(Clone, Debug)]
+pub enum CameraCommand {
+    /// Move through `points` along a smooth spline, ending on the last one.
+    MoveAlong { points: Vec<Vec2>, seconds: f32 },
+    /// Stay put.
+    Hold { seconds: f32 },
+    /// Tween the [`CameraZoom`] (`2` shows twice as much of the world).
+    Zoom { scale: f32, seconds: f32 },
+    /// Tween the zoom back to where it was when the sequence started, then hand the camera back to
+    /// the [`PixelCameraTracked`] entities.
+    Follow { seconds: f32 },
+}
+
+/// A named list of [`CameraCommand`]s, built with the chained helpers.
+#[derive(Clone, Debug, Default)]
+pub struct CameraSequence {
+    pub name: String,
+    pub commands: Vec<CameraCommand>,
+}
+
+impl CameraSequence {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            commands: Vec::new(),
+        }
+    }
+
+    pub fn move_to(self, target: Vec2, seconds: f32) -> Self {
+        self.move_along(vec![target], seconds)
+    }
+
+    pub fn move_along(mut self, points: Vec<Vec2>, seconds: f32) -> Self {
+        self.commands
+            .push(CameraCommand::MoveAlong { points, seconds });
+        self
+    }
+
+    pub fn hold(mut self, seconds: f32) -> Self {
+        self.commands.push(CameraCommand::Hold { seconds });
+        self
+    }
+
+    pub fn zoom(mut self, scale: f32, seconds: f32) -> Self {
+        self.commands.push(CameraCommand::Zoom { scale, seconds });
+        self
+    }
+
+    pub fn follow(mut self, seconds: f32) -> Self {
+        self.commands.push(CameraCommand::Follow { seconds });
+        self
+    }
+}
+
+/// Sequences that map trigger areas can start by name.
+#[derive(Resource, Default)]
+pub struct CameraSequences(pub HashMap<String, CameraSequence>);
+
+/// Sent when a [`CameraSequence`] played by the [`CameraDirector`] is done.
+#[derive(Event, Debug)]
+pub struct CameraSequenceFinished {
+    pub name: String,
+}
+
+/// Takes control of the camera away from following while it plays queued [`CameraSequence`]s.
+#[derive(Resource, Default)]
+pub struct CameraDirector {
+    queue: VecDeque<CameraSequence>,
+    current: Option<RunningSequence>,
+}
+
+impl CameraDirector {
+    pub fn play(&mut self, sequence: CameraSequence) {
+        self.queue.push_back(sequence);
+    }
+
+    /// Drops the current and queued sequences, handing the camera straight back to following.
+    pub fn stop(&mut self) {
+        self.queue.clear();
+        self.current = None;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.current.is_some() || !self.queue.is_empty()
+    }
+}
+
+pub(crate) fn camera_director_idle(director: Res<CameraDirector>) -> bool {
+    !director.is_active()
+}
+
+struct RunningSequence {
+    name: String,
+    commands: VecDeque<CameraCommand>,
+    step: Option<RunningStep>,
+    start_scale: f32,
+}
+
+struct RunningStep {
+    command: CameraCommand,
+    timer: Timer,
+    from_position: Vec2,
+}
+
+impl RunningStep {
+    fn new(command: CameraCommand, position: Vec2) -> Self {
+        let seconds = match &command {
+            CameraCommand::MoveAlong { seconds, .. }
+            | CameraCommand::Hold { seconds }
+            | CameraCommand::Zoom { seconds, .. }
+            | CameraCommand::Follow { seconds } => seconds.max(0.),
+        };
+
+        Self {
+            command,
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+            from_position: position,
+        }
+    }
+}
+
+pub(crate) fn camera_director_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut director: ResMut<CameraDirector>,
+    mut camera: Query<(&mut Transform, &mut CameraZoom, &mut CameraFollowState), With<PixelCamera>>,
+) {
+    let Ok((mut transform, mut zoom, mut state)) = camera.get_single_mut() else {
+        return;
+    };
+
+    let director = director.as_mut();
+
+    if director.current.is_none() {
+        let Some(sequence) = director.queue.pop_front() else {
+            return;
+        };
+
+        director.current = Some(RunningSequence {
+            name: sequence.name,
+            commands: sequence.commands.into(),
+            step: None,
+            start_scale: zoom.target(),
+        });
+    }
+
+    let Some(running) = director.current.as_mut() else {
+        return;
+    };
+
+    loop {
+        if running.step.is_none() {
+            let Some(command) = running.commands.pop_front() else {
+                break;
+            };
+
+            match &command {
+                CameraCommand::Zoom { scale, seconds } => zoom.zoom_to(*scale, *seconds),
+                CameraCommand::Follow { seconds } => zoom.zoom_to(running.start_scale, *seconds),
+                _ => {}
+            }
+
+            running.step = Some(RunningStep::new(command, transform.translation.truncate()));
+        }
+
+        let Some(step) = running.step.as_mut() else {
+            break;
+        };
+
+        step.timer.tick(time.delta());
+
+        if let CameraCommand::MoveAlong { points, .. } = &step.command {
+            let t = step.timer.fraction();
+            let position = catmull_rom_path(step.from_position, points, t * t * (3. - 2. * t));
+
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+        }
+
+        if !step.timer.finished() {
+            return;
+        }
+
+        running.step = None;
+    }
+
+    commands.send_event(CameraSequenceFinished {
+        name: running.name.clone(),
+    });
+
+    director.current = None;
+    state.focus = None;
+    state.velocity = Vec2::ZERO;
+}
+
+/// Samples a Catmull-Rom spline going from `start` through every point of `points`, with `t` in `0..=1`.
+fn catmull_rom_path(start: Vec2, points: &[Vec2], t: f32) -> Vec2 {
+    let Some(&end) = points.last() else {
+        return start;
+    };
+
+    let path: Vec<Vec2> = std::iter::once(start)
+        .chain(points.iter().copied())
+        .collect();
+    let segments = path.len() - 1;
+    let scaled = t.clamp(0., 1.) * segments as f32;
+    let segment = (scaled.floor() as usize).min(segments - 1);
+    let local_t = scaled - segment as f32;
+
+    if t >= 1. {
+        return end;
+    }
+
+    let point = |index: isize| path[index.clamp(0, segments as isize) as usize];
+    let index = segment as isize;
+    let (p0, p1, p2, p3) = (
+        point(index - 1),
+        point(index),
+        point(index + 1),
+        point(index + 2),
+    );
+
+    let t2 = local_t * local_t;
+    let t3 = t2 * local_t;
+
+    0.5 * ((2. * p1)
+        + (p2 - p0) * local_t
+        + (2. * p0 - 5. * p1 + 4. * p2 - p3) * t2
+        + (3. * p1 - p0 - 3. * p2 + p3) * t3)
+}
+
+/// Starts the named [`CameraSequence`] when a [`PixelCameraTracked`] entity enters `area`.
+#[derive(Component, Clone, Debug)]
+pub struct CameraSequenceTrigger {
+    pub sequence: String,
+    /// Area relative to the entity position.
+    pub area: Rect,
+    /// Only start the sequence the first time the area is entered.
+    pub once: bool,
+    occupied: bool,
+    fired: bool,
+}
+
+impl CameraSequenceTrigger {
+    pub fn new(sequence: impl Into<String>, area: Rect) -> Self {
+        Self {
+            sequence: sequence.into(),
+            area,
+            once: false,
+            occupied: false,
+            fired: false,
+        }
+    }
+
+    pub fn once(mut self) -> Self {
+        self.once = true;
+        self
+    }
+}
+
+pub(crate) fn camera_sequence_trigger_system(
+    sequences: Res<CameraSequences>,
+    mut director: ResMut<CameraDirector>,
+    mut triggers: Query<(&mut CameraSequenceTrigger, &GlobalTransform)>,
+    tracked: Query<&GlobalTransform, With<PixelCameraTracked>>,
+) {
+    for (mut trigger, global_transform) in triggers.iter_mut() {
+        if trigger.once && trigger.fired {
+            continue;
+        }
+
+        let offset = global_transform.translation().truncate();
+        let area = Rect::from_corners(trigger.area.min + offset, trigger.area.max + offset);
+        let occupied = tracked
+            .iter()
+            .any(|transform| area.contains(transform.translation().truncate()));
+
+        if occupied && !trigger.occupied {
+            match sequences.0.get(&trigger.sequence) {
+                Some(sequence) => {
+                    director.play(sequence.clone());
+                    trigger.fired = true;
+                }
+                None => warn!("Unknown camera sequence {}", trigger.sequence),
+            }
+        }
+
+        trigger.occupied = occupied;
+    }
+}
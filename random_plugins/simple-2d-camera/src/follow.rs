@@ -0,0 +1,134 @@
+use bevy::prelude::*;
+
+/// Controls how the camera follows the [`PixelCameraTracked`](crate::PixelCameraTracked) entities.
+///
+/// The camera keeps a focus point and only moves it when the tracked point leaves the dead-zone
+/// rectangle around it. The camera then eases towards the focus point with a critically damped spring.
+#[derive(Component, Clone, Debug)]
+pub struct CameraFollowSettings {
+    /// Half extents of the dead-zone rectangle, in world pixels.
+    pub dead_zone: Vec2,
+    /// Offset of the dead-zone center relative to the camera.
+    pub dead_zone_offset: Vec2,
+    /// Spring stiffness per axis (angular frequency, higher is snappier). `0` disables following on that axis.
+    pub damping: Vec2,
+    /// While airborne, only follow vertically once the tracked point leaves `airborne_dead_zone`.
+    pub lock_vertical_while_airborne: bool,
+    /// Half height of the vertical dead-zone used while airborne.
+    pub airborne_dead_zone: f32,
+    /// Snap the vertical focus to the tracked entity when it lands on a platform.
+    pub platform_snapping: bool,
+    /// Maximum horizontal look-ahead offset, in world pixels. `0` disables look-ahead.
+    pub look_ahead_distance: f32,
+    /// Seconds of horizontal velocity to look ahead by, before clamping to `look_ahead_distance`.
+    pub look_ahead_time: f32,
+    /// Horizontal speed below which the look-ahead eases back to the center.
+    pub look_ahead_min_speed: f32,
+    /// How fast the look-ahead offset catches up with its target (per second, higher is snappier).
+    pub look_ahead_smoothing: f32,
+}
+
+impl Default for CameraFollowSettings {
+    fn default() -> Self {
+        Self {
+            dead_zone: Vec2::new(30., 30.),
+            dead_zone_offset: Vec2::ZERO,
+            damping: Vec2::new(6., 4.),
+            lock_vertical_while_airborne: true,
+            airborne_dead_zone: 100.,
+            platform_snapping: true,
+            look_ahead_distance: 40.,
+            look_ahead_time: 0.3,
+            look_ahead_min_speed: 20.,
+            look_ahead_smoothing: 3.,
+        }
+    }
+}
+
+#[derive(Component, Default, Debug)]
+pub struct CameraFollowState {
+    pub focus: Option<Vec2>,
+    pub velocity: Vec2,
+    pub look_ahead: f32,
+}
+
+impl CameraFollowSettings {
+    /// Moves `focus` so that `track_point` ends up inside the dead-zone.
+    pub fn update_focus(&self, focus: Vec2, track_point: Vec2, grounded: bool) -> Vec2 {
+        let center = focus + self.dead_zone_offset;
+        let min = center - self.dead_zone;
+        let max = center + self.dead_zone;
+
+        let mut focus = focus;
+
+        if track_point.x < min.x {
+            focus.x += track_point.x - min.x;
+        } else if track_point.x > max.x {
+            focus.x += track_point.x - max.x;
+        }
+
+        if grounded && self.platform_snapping {
+            focus.y = track_point.y - self.dead_zone_offset.y;
+            return focus;
+        }
+
+        let (min_y, max_y) = if !grounded && self.lock_vertical_while_airborne {
+            (center.y - self.airborne_dead_zone, center.y + self.airborne_dead_zone)
+        } else {
+            (min.y, max.y)
+        };
+
+        if track_point.y < min_y {
+            focus.y += track_point.y - min_y;
+        } else if track_point.y > max_y {
+            focus.y += track_point.y - max_y;
+        }
+
+        focus
+    }
+
+    /// Eases the current look-ahead offset towards the one matching `velocity_x`.
+    ///
+    /// The offset follows an exponential curve so it never overshoots, and a quick turnaround
+    /// only swings it smoothly to the other side instead of bouncing the camera back and forth.
+    pub fn update_look_ahead(&self, look_ahead: f32, velocity_x: f32, delta_t: f32) -> f32 {
+        if self.look_ahead_distance <= 0. {
+            return 0.;
+        }
+
+        let target = if velocity_x.abs() < self.look_ahead_min_speed {
+            0.
+        } else {
+            (velocity_x * self.look_ahead_time)
+                .clamp(-self.look_ahead_distance, self.look_ahead_distance)
+        };
+
+        let blend = 1. - (-self.look_ahead_smoothing * delta_t).exp();
+
+        look_ahead + (target - look_ahead) * blend
+    }
+}
+
+/// Implicit critically damped spring step, stable for any `delta_t`.
+pub fn critically_damped_step(
+    position: f32,
+    velocity: f32,
+    target: f32,
+    frequency: f32,
+    delta_t: f32,
+) -> (f32, f32) {
+    if frequency <= 0. {
+        return (position, 0.);
+    }
+
+    let f = 1. + 2. * delta_t * frequency;
+    let oo = frequency * frequency;
+    let hoo = delta_t * oo;
+    let hhoo = delta_t * hoo;
+    let det_inv = 1. / (f + hhoo);
+
+    let next_position = (f * position + delta_t * velocity + hhoo * target) * det_inv;
+    let next_velocity = (velocity + hoo * (target - position)) * det_inv;
+
+    (next_position, next_velocity)
+}
@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+
+use crate::PixelCameraTracked;
+
+/// Controls how the camera frames several [`PixelCameraTracked`] entities at once.
+#[derive(Component, Clone, Debug)]
+pub struct CameraFramingSettings {
+    /// Extra space kept between the tracked entities and the screen edges, in world pixels.
+    pub margin: Vec2,
+    /// Largest integer zoom-out factor used to keep every tracked entity on screen. `1` disables zooming.
+    pub max_zoom_out: u32,
+    /// Fraction of the next smaller view the targets must fit in before zooming back in, to avoid flickering.
+    pub zoom_in_threshold: f32,
+}
+
+impl Default for CameraFramingSettings {
+    fn default() -> Self {
+        Self {
+            margin: Vec2::new(48., 32.),
+            max_zoom_out: 2,
+            zoom_in_threshold: 0.9,
+        }
+    }
+}
+
+/// Weighted summary of all tracked entities.
+pub(crate) struct TrackedFrame {
+    pub center: Vec2,
+    pub velocity: Vec2,
+    pub grounded: bool,
+    /// Half extents of the box around `center` containing every tracked entity.
+    pub half_extents: Vec2,
+}
+
+impl TrackedFrame {
+    /// Returns `None` when there is nothing with a positive weight to track.
+    pub fn from_targets<'a>(
+        targets: impl IntoIterator<Item = (Vec2, Vec2, &'a PixelCameraTracked)>,
+    ) -> Option<Self> {
+        let targets: Vec<_> = targets
+            .into_iter()
+            .filter(|(_, _, tracked)| tracked.weight > 0.)
+            .collect();
+
+        let total_weight: f32 = targets.iter().map(|(_, _, tracked)| tracked.weight).sum();
+
+        if total_weight <= 0. {
+            return None;
+        }
+
+        let center = targets
+            .iter()
+            .map(|(position, _, tracked)| *position * tracked.weight)
+            .sum::<Vec2>()
+            / total_weight;
+
+        let velocity = targets
+            .iter()
+            .map(|(_, velocity, tracked)| *velocity * tracked.weight)
+            .sum::<Vec2>()
+            / total_weight;
+
+        let half_extents = targets
+            .iter()
+            .fold(Vec2::ZERO, |half_extents, (position, _, _)| {
+                half_extents.max((*position - center).abs())
+            });
+
+        Some(Self {
+            center,
+            velocity,
+            grounded: targets.iter().all(|(_, _, tracked)| tracked.grounded),
+            half_extents,
+        })
+    }
+}
+
+impl CameraFramingSettings {
+    /// Picks the integer zoom-out factor needed to keep `half_extents` on screen.
+    pub fn zoom_out_for(&self, current: u32, half_extents: Vec2, view_half_size: Vec2) -> u32 {
+        let max_zoom_out = self.max_zoom_out.max(1);
+        let needed = half_extents + self.margin;
+        let fits = |zoom: u32, slack: f32| {
+            let view = view_half_size * zoom as f32 * slack;
+            needed.x <= view.x && needed.y <= view.y
+        };
+
+        let mut zoom = current.clamp(1, max_zoom_out);
+
+        while zoom < max_zoom_out && !fits(zoom, 1.) {
+            zoom += 1;
+        }
+
+        while zoom > 1 && fits(zoom - 1, self.zoom_in_threshold) {
+            zoom -= 1;
+        }
+
+        zoom
+    }
+}
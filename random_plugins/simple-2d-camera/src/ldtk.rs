@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::ldtk::Level;
+use bevy_ecs_ldtk::prelude::*;
+
+use crate::{CameraBounds, CameraRoom, CameraSequenceTrigger, CameraZoomZone};
+
+/// LDtk entities named `CameraSequenceTrigger` become [`CameraSequenceTrigger`]s covering the entity.
+///
+/// The `sequence` string field names the sequence to play, and the optional `once` bool field only plays it once.
+pub const LDTK_CAMERA_SEQUENCE_TRIGGER_IDENTIFIER: &str = "CameraSequenceTrigger";
+
+/// LDtk entities named `CameraZoomZone` become [`CameraZoomZone`]s covering the entity.
+///
+/// The `scale` float field is the zoom to use inside the zone, and the optional `seconds` float field the zoom duration.
+pub const LDTK_CAMERA_ZOOM_ZONE_IDENTIFIER: &str = "CameraZoomZone";
+
+pub(crate) fn setup_ldtk_camera(app: &mut App) {
+    app.add_systems(PostUpdate, ldtk_level_bounds_system);
+    app.register_ldtk_entity::<CameraSequenceTriggerBundle>(
+        LDTK_CAMERA_SEQUENCE_TRIGGER_IDENTIFIER,
+    );
+    app.register_ldtk_entity::<CameraZoomZoneBundle>(LDTK_CAMERA_ZOOM_ZONE_IDENTIFIER);
+}
+
+#[derive(Bundle, LdtkEntity)]
+struct CameraSequenceTriggerBundle {
+    #[with(camera_sequence_trigger_from_fields)]
+    trigger: CameraSequenceTrigger,
+}
+
+fn camera_sequence_trigger_from_fields(entity_instance: &EntityInstance) -> CameraSequenceTrigger {
+    let sequence = entity_instance
+        .get_string_field("sequence")
+        .cloned()
+        .unwrap_or_default();
+
+    let size = Vec2::new(entity_instance.width as f32, entity_instance.height as f32);
+    let trigger = CameraSequenceTrigger::new(sequence, Rect::from_center_size(Vec2::ZERO, size));
+
+    match entity_instance.get_bool_field("once") {
+        Ok(true) => trigger.once(),
+        _ => trigger,
+    }
+}
+
+#[derive(Bundle, LdtkEntity)]
+struct CameraZoomZoneBundle {
+    #[with(camera_zoom_zone_from_fields)]
+    zone: CameraZoomZone,
+}
+
+fn camera_zoom_zone_from_fields(entity_instance: &EntityInstance) -> CameraZoomZone {
+    let scale = entity_instance
+        .get_float_field("scale")
+        .copied()
+        .unwrap_or(1.);
+
+    let size = Vec2::new(entity_instance.width as f32, entity_instance.height as f32);
+    let zone = CameraZoomZone::new(scale, Rect::from_center_size(Vec2::ZERO, size));
+
+    match entity_instance.get_float_field("seconds") {
+        Ok(seconds) => zone.with_seconds(*seconds),
+        Err(_) => zone,
+    }
+}
+
+/// Every spawned level becomes a [`CameraRoom`], while the bounds follow the [`LevelSelection`] so that neighbour levels
+/// loaded alongside it don't take them over.
+fn ldtk_level_bounds_system(
+    mut commands: Commands,
+    mut level_events: EventReader<LevelEvent>,
+    projects: Res<Assets<LdtkProject>>,
+    project_query: Query<&LdtkProjectHandle>,
+    level_selection: Option<Res<LevelSelection>>,
+    levels: Query<(Entity, &LevelIid, &GlobalTransform)>,
+    mut bounds: ResMut<CameraBounds>,
+) {
+    let raw_levels = || {
+        project_query
+            .iter()
+            .filter_map(|handle| projects.get(&handle.handle))
+    };
+
+    let level_rect = |level: &Level, level_transform: &GlobalTransform| {
+        let min = level_transform.translation().truncate();
+        let size = Vec2::new(level.px_wid as f32, level.px_hei as f32);
+
+        Rect::from_corners(min, min + size)
+    };
+
+    let mut levels_changed = false;
+
+    for level_event in level_events.read() {
+        match level_event {
+            LevelEvent::Transformed(level_iid) => {
+                levels_changed = true;
+
+                let Some(level) =
+                    raw_levels().find_map(|project| project.get_raw_level_by_iid(level_iid.get()))
+                else {
+                    continue;
+                };
+
+                let Some((level_entity, _, level_transform)) =
+                    levels.iter().find(|(_, iid, _)| *iid == level_iid)
+                else {
+                    continue;
+                };
+
+                commands
+                    .entity(level_entity)
+                    .insert(CameraRoom(level_rect(level, level_transform)));
+            }
+            LevelEvent::Despawned(_) => levels_changed = true,
+            _ => {}
+        }
+    }
+
+    let selection_changed = level_selection
+        .as_ref()
+        .is_some_and(|level_selection| level_selection.is_changed());
+
+    if !levels_changed && !selection_changed {
+        return;
+    }
+
+    let selected_bounds = level_selection.and_then(|level_selection| {
+        let level = raw_levels()
+            .find_map(|project| project.find_raw_level_by_level_selection(&level_selection))?;
+
+        levels
+            .iter()
+            .find(|(_, iid, _)| *iid.get() == level.iid)
+            .map(|(_, _, level_transform)| level_rect(level, level_transform))
+    });
+
+    bounds.0 = selected_bounds;
+}
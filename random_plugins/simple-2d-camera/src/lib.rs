@@ -1,20 +1,62 @@
 use avian2d::prelude::LinearVelocity;
 use bevy::prelude::*;
 use bevy::render::camera::ScalingMode;
-use bevy_trauma_shake::{Shake, TraumaPlugin};
+use bevy::render::view::VisibilitySystems;
+use bevy::transform::TransformSystem;
+use director::{camera_director_idle, camera_director_system, camera_sequence_trigger_system};
+use follow::critically_damped_step;
+use framing::TrackedFrame;
+use parallax::{parallax_setup_system, parallax_system};
+use pixel_perfect::{
+    create_pixel_canvas, pixel_camera_target, pixel_canvas_scale_system, pixel_snap_system,
+    spawn_pixel_canvas, PixelCanvas,
+};
+use rooms::camera_room_system;
+use shake::{camera_shake_event_system, camera_shake_system, CameraShakeState};
+use zoom::{camera_zoom_system, camera_zoom_zone_system};
 
-pub const CAMERA_TRACK_SPEED: f32 = 200.;
-pub const CAMERA_TRACK_SPEED_FAST: f32 = 1000.;
-pub const SPEED_CAMERA_TRACK_FACTOR: f32 = 0.0;
+pub use bounds::{clamp_to_bounds, CameraBounds};
+pub use director::{
+    CameraCommand, CameraDirector, CameraSequence, CameraSequenceFinished, CameraSequenceTrigger,
+    CameraSequences,
+};
+pub use follow::{CameraFollowSettings, CameraFollowState};
+pub use framing::CameraFramingSettings;
+pub use parallax::ParallaxLayer;
+pub use pixel_perfect::{PixelCameraViewport, PIXEL_CANVAS_LAYER};
+pub use rooms::{ActiveCameraRoom, CameraRoom, CameraRoomSettings, RoomTransition};
+pub use shake::{CameraShakeEvent, CameraShakeSettings};
+pub use zoom::{CameraZoom, CameraZoomZone};
+
+mod bounds;
+mod director;
+mod follow;
+mod framing;
+#[cfg(feature = "ldtk")]
+mod ldtk;
+mod parallax;
+mod pixel_perfect;
+mod rooms;
+mod shake;
+mod zoom;
+#[cfg(feature = "tiled")]
+mod tiled_map;
 
 pub struct SimplePixel2dCameraPlugin {
     pub screen_size: Vec2,
+    pub follow_settings: CameraFollowSettings,
+    pub framing_settings: CameraFramingSettings,
+    /// Render at `screen_size` into an offscreen image, snap the camera to whole pixels and upscale by an integer factor.
+    pub pixel_perfect: bool,
 }
 
 impl Default for SimplePixel2dCameraPlugin {
     fn default() -> Self {
         Self {
             screen_size: Vec2::new(480.0, 270.0),
+            follow_settings: CameraFollowSettings::default(),
+            framing_settings: CameraFramingSettings::default(),
+            pixel_perfect: false,
         }
     }
 }
@@ -22,24 +64,31 @@ impl Default for SimplePixel2dCameraPlugin {
 #[derive(Resource)]
 struct PixelCameraResolution(Vec2);
 
-#[derive(Component, Default)]
-pub struct PixelCameraTracked;
+#[derive(Resource)]
+struct PixelCameraFollowSettings(CameraFollowSettings);
+
+#[derive(Resource)]
+struct PixelCameraFramingSettings(CameraFramingSettings);
+
+/// Marks the game camera, as opposed to the one presenting the pixel-perfect canvas.
+#[derive(Component)]
+pub struct PixelCamera;
 
+/// Marks an entity the camera should follow.
+///
+/// `grounded` is kept up to date by the player controllers and drives the vertical follow behaviour.
+/// With several tracked entities, the camera frames them all and leans towards the ones with a higher `weight`.
 #[derive(Component)]
-pub struct CameraShake {
-    pub started_at: f64,
-    pub duration: f64,
-    pub intensity: f32,
-    pub timer: Timer
+pub struct PixelCameraTracked {
+    pub grounded: bool,
+    pub weight: f32,
 }
 
-impl CameraShake {
-    pub fn soft(now: f64, duration: f64) -> Self {
+impl Default for PixelCameraTracked {
+    fn default() -> Self {
         Self {
-            started_at: now,
-            duration,
-            intensity: 8.0,
-            timer: Timer::from_seconds(0.05, TimerMode::Repeating),
+            grounded: false,
+            weight: 1.,
         }
     }
 }
@@ -47,41 +96,184 @@ impl CameraShake {
 impl Plugin for SimplePixel2dCameraPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(PixelCameraResolution(self.screen_size));
-        app.add_plugins(TraumaPlugin);
+        app.insert_resource(PixelCameraFollowSettings(self.follow_settings.clone()));
+        app.insert_resource(PixelCameraFramingSettings(self.framing_settings.clone()));
+        app.init_resource::<CameraBounds>();
+        app.init_resource::<CameraRoomSettings>();
+        app.init_resource::<ActiveCameraRoom>();
+        app.init_resource::<CameraShakeSettings>();
+        app.add_event::<CameraShakeEvent>();
+        app.init_resource::<CameraDirector>();
+        app.init_resource::<CameraSequences>();
+        app.add_event::<CameraSequenceFinished>();
         app.add_systems(Startup, start_camera_system);
-        app.add_systems(Update, (camera_track_system));
+        app.add_systems(
+            Update,
+            (
+                camera_sequence_trigger_system,
+                camera_director_system,
+                camera_zoom_zone_system,
+                camera_zoom_system,
+                camera_room_system.run_if(camera_director_idle),
+                camera_track_system.run_if(camera_director_idle),
+                parallax_setup_system,
+                parallax_system,
+                camera_shake_event_system,
+            )
+                .chain(),
+        );
+        app.add_systems(
+            PostUpdate,
+            camera_shake_system
+                .after(TransformSystem::TransformPropagate)
+                .before(VisibilitySystems::UpdateFrusta),
+        );
+
+        if self.pixel_perfect {
+            app.insert_resource(PixelCanvas {
+                resolution: self.screen_size,
+                ..default()
+            });
+            app.add_systems(Update, pixel_canvas_scale_system);
+            app.add_systems(
+                PostUpdate,
+                pixel_snap_system
+                    .after(camera_shake_system)
+                    .before(VisibilitySystems::UpdateFrusta),
+            );
+        }
+
+        #[cfg(feature = "tiled")]
+        tiled_map::setup_tiled_camera(app);
+
+        #[cfg(feature = "ldtk")]
+        ldtk::setup_ldtk_camera(app);
     }
 }
 
+type TrackedCameraQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Transform,
+        &'static mut OrthographicProjection,
+        &'static CameraFollowSettings,
+        &'static CameraFramingSettings,
+        &'static mut CameraFollowState,
+        &'static mut CameraZoom,
+    ),
+    With<PixelCamera>,
+>;
+
 fn camera_track_system(
     time: Res<Time>,
-    mut camera: Query<&mut Transform, With<Camera>>,
-    tracked: Query<(&Transform, &LinearVelocity), (With<PixelCameraTracked>, Without<Camera>)>,
+    camera_resolution: Res<PixelCameraResolution>,
+    bounds: Res<CameraBounds>,
+    active_room: Res<ActiveCameraRoom>,
+    rooms: Query<&CameraRoom>,
+    mut camera: TrackedCameraQuery,
+    tracked: Query<(&Transform, &LinearVelocity, &PixelCameraTracked), Without<PixelCamera>>,
 ) {
-    let mut camera = camera.single_mut();
+    let Ok((mut camera, mut projection, settings, framing, mut state, mut zoom)) =
+        camera.get_single_mut()
+    else {
+        return;
+    };
 
-    for (transform, velocity) in tracked.iter() {
-        let track_point = calculate_camera_track_point(transform, velocity);
-        let dx = track_point.x - camera.translation.x;
-        let dy = track_point.y - camera.translation.y;
+    if active_room.is_transitioning() {
+        return;
+    }
 
-        let speed_window_x = (dx.abs().clamp(30., 150.) - 30.) / 120.;
+    let Some(frame) = TrackedFrame::from_targets(
+        tracked
+            .iter()
+            .map(|(transform, velocity, tracked)| (transform.translation.truncate(), velocity.0, tracked)),
+    ) else {
+        return;
+    };
 
-        if dx.abs() >= 30. {
-            camera.translation.x += dx.signum() * CAMERA_TRACK_SPEED_FAST * speed_window_x * time.delta_secs();
-        }
+    let resolution_half_size = camera_resolution.0 / 2.;
+    zoom.framing = framing.zoom_out_for(
+        zoom.framing,
+        frame.half_extents,
+        resolution_half_size * zoom.current(),
+    );
+
+    if projection.scale != zoom.scale() {
+        projection.scale = zoom.scale();
+    }
 
-        if dy.abs() >= 100. {
-            camera.translation.y += dy.signum() * CAMERA_TRACK_SPEED_FAST * time.delta_secs();
-        } else if dy.abs() >= 30. {
-            camera.translation.y += dy.signum() * CAMERA_TRACK_SPEED * time.delta_secs();
+    let camera_position = camera.translation.truncate();
+    let delta_t = time.delta_secs();
+
+    let previous_focus = state.focus.unwrap_or(camera_position - Vec2::X * state.look_ahead);
+    let focus = settings.update_focus(previous_focus, frame.center, frame.grounded);
+    let look_ahead = settings.update_look_ahead(state.look_ahead, frame.velocity.x, delta_t);
+
+    let (x, velocity_x) = critically_damped_step(
+        camera_position.x,
+        state.velocity.x,
+        focus.x + look_ahead,
+        settings.damping.x,
+        delta_t,
+    );
+    let (y, velocity_y) = critically_damped_step(
+        camera_position.y,
+        state.velocity.y,
+        focus.y,
+        settings.damping.y,
+        delta_t,
+    );
+
+    let mut position = Vec2::new(x, y);
+    let mut velocity = Vec2::new(velocity_x, velocity_y);
+
+    let room_bounds = active_room
+        .room
+        .and_then(|room| rooms.get(room).ok())
+        .map(|room| room.0);
+
+    if let Some(bounds) = room_bounds.or(bounds.0) {
+        let clamped = clamp_to_bounds(position, resolution_half_size * projection.scale, bounds);
+
+        if clamped.x != position.x {
+            velocity.x = 0.;
         }
+        if clamped.y != position.y {
+            velocity.y = 0.;
+        }
+
+        position = clamped;
     }
+
+    camera.translation.x = position.x;
+    camera.translation.y = position.y;
+    state.focus = Some(focus);
+    state.look_ahead = look_ahead;
+    state.velocity = velocity;
 }
 
-fn start_camera_system(mut commands: Commands, camera_resolution: Res<PixelCameraResolution>) {
+fn start_camera_system(
+    mut commands: Commands,
+    camera_resolution: Res<PixelCameraResolution>,
+    follow_settings: Res<PixelCameraFollowSettings>,
+    framing_settings: Res<PixelCameraFramingSettings>,
+    mut canvas: Option<ResMut<PixelCanvas>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let camera = match canvas.as_deref_mut() {
+        Some(canvas) => {
+            canvas.image = create_pixel_canvas(&mut images, canvas.resolution);
+            spawn_pixel_canvas(&mut commands, canvas);
+            pixel_camera_target(canvas)
+        }
+        None => Camera::default(),
+    };
+
     commands.spawn((
         Camera2d,
+        camera,
+        PixelCamera,
         OrthographicProjection {
             scaling_mode: ScalingMode::Fixed {
                 width: camera_resolution.0.x,
@@ -91,15 +283,10 @@ fn start_camera_system(mut commands: Commands, camera_resolution: Res<PixelCamer
             far: 1000.,
             ..OrthographicProjection::default_2d()
         },
-        Shake::default()
+        CameraShakeState::default(),
+        follow_settings.0.clone(),
+        framing_settings.0.clone(),
+        CameraFollowState::default(),
+        CameraZoom::new(canvas.is_some()),
     ));
 }
-
-fn calculate_camera_track_point(transform: &Transform, velocity: &LinearVelocity) -> Vec2 {
-    let x_speed = velocity.x;
-
-    let track_x = transform.translation.x + x_speed * SPEED_CAMERA_TRACK_FACTOR;
-    let track_y = transform.translation.y;
-
-    Vec2::new(track_x, track_y)
-}
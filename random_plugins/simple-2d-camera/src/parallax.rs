@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+
+use crate::PixelCamera;
+
+/// Moves a sprite relative to the camera to fake depth.
+///
+/// A `factor` of `1` moves with the world, `0` sticks to the screen. Repeated axes tile the sprite image
+/// infinitely around the camera.
+#[derive(Component, Clone, Debug)]
+pub struct ParallaxLayer {
+    pub factor: Vec2,
+    pub repeat_x: bool,
+    pub repeat_y: bool,
+}
+
+impl Default for ParallaxLayer {
+    fn default() -> Self {
+        Self {
+            factor: Vec2::splat(0.5),
+            repeat_x: false,
+            repeat_y: false,
+        }
+    }
+}
+
+/// Where the layer sits (`translation`, local to its parent) when the camera is at `camera_position`.
+///
+/// Defaults to the spawn position and the camera position at that time.
+#[derive(Component, Clone, Copy, Debug)]
+pub(crate) struct ParallaxOrigin {
+    pub translation: Vec3,
+    pub camera_position: Vec2,
+}
+
+pub(crate) fn parallax_setup_system(
+    mut commands: Commands,
+    camera: Query<&Transform, With<PixelCamera>>,
+    layers: Query<(Entity, &Transform, Has<ParallaxOrigin>), Added<ParallaxLayer>>,
+) {
+    let camera_position = camera
+        .get_single()
+        .map(|camera| camera.translation.truncate())
+        .unwrap_or_default();
+
+    for (entity, transform, has_origin) in layers.iter() {
+        if has_origin {
+            continue;
+        }
+
+        commands.entity(entity).insert(ParallaxOrigin {
+            translation: transform.translation,
+            camera_position,
+        });
+    }
+}
+
+type ParallaxLayerQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static ParallaxLayer,
+        &'static ParallaxOrigin,
+        &'static mut Transform,
+        &'static GlobalTransform,
+        Option<&'static mut Sprite>,
+    ),
+    Without<PixelCamera>,
+>;
+
+pub(crate) fn parallax_system(
+    images: Res<Assets<Image>>,
+    camera: Query<(&Transform, &OrthographicProjection), With<PixelCamera>>,
+    mut layers: ParallaxLayerQuery,
+) {
+    let Ok((camera, projection)) = camera.get_single() else {
+        return;
+    };
+
+    let camera_position = camera.translation.truncate();
+    let view_size = projection.area.size();
+
+    for (layer, origin, mut transform, global_transform, sprite) in layers.iter_mut() {
+        let parent_offset =
+            global_transform.translation().truncate() - transform.translation.truncate();
+        let offset = (camera_position - origin.camera_position) * (Vec2::ONE - layer.factor);
+        let mut translation = origin.translation.truncate() + offset;
+
+        let tile_size = sprite
+            .as_ref()
+            .and_then(|sprite| images.get(&sprite.image))
+            .map(|image| image.size_f32());
+
+        if let (Some(mut sprite), Some(tile_size)) = (sprite, tile_size) {
+            if layer.repeat_x || layer.repeat_y {
+                let mut size = tile_size;
+                let world_center = parent_offset + translation;
+                let distance = world_center - camera_position;
+
+                if layer.repeat_x {
+                    size.x = covering_size(view_size.x, tile_size.x);
+                    translation.x += wrap(distance.x, tile_size.x) - distance.x;
+                }
+
+                if layer.repeat_y {
+                    size.y = covering_size(view_size.y, tile_size.y);
+                    translation.y += wrap(distance.y, tile_size.y) - distance.y;
+                }
+
+                if sprite.custom_size != Some(size) {
+                    sprite.custom_size = Some(size);
+                    sprite.image_mode = SpriteImageMode::Tiled {
+                        tile_x: layer.repeat_x,
+                        tile_y: layer.repeat_y,
+                        stretch_value: 1.,
+                    };
+                }
+            }
+        }
+
+        transform.translation.x = translation.x;
+        transform.translation.y = translation.y;
+    }
+}
+
+/// An odd number of tiles wide enough to cover the view wherever the tile is wrapped to, so tiles stay centered.
+fn covering_size(view_size: f32, tile_size: f32) -> f32 {
+    let half_count = (view_size / tile_size / 2.).ceil();
+
+    (half_count * 2. + 1.) * tile_size
+}
+
+/// Wraps `distance` into `-tile_size / 2..tile_size / 2`.
+fn wrap(distance: f32, tile_size: f32) -> f32 {
+    (distance + tile_size / 2.).rem_euclid(tile_size) - tile_size / 2.
+}
@@ -0,0 +1,160 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::render::camera::RenderTarget;
+use bevy::render::render_resource::{
+    Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+};
+use bevy::render::view::RenderLayers;
+use bevy::window::PrimaryWindow;
+
+use crate::PixelCamera;
+
+/// Render layer used by the upscaled canvas and the camera presenting it to the window.
+pub const PIXEL_CANVAS_LAYER: usize = 1;
+
+/// Present in pixel-perfect mode: the game renders to `image` at the logical resolution,
+/// which is then upscaled to the window by `scale`.
+#[derive(Resource, Default)]
+pub(crate) struct PixelCanvas {
+    pub image: Handle<Image>,
+    pub resolution: Vec2,
+    pub scale: f32,
+}
+
+#[derive(Component)]
+pub(crate) struct PixelCanvasSprite;
+
+pub(crate) fn create_pixel_canvas(images: &mut Assets<Image>, resolution: Vec2) -> Handle<Image> {
+    let size = Extent3d {
+        width: resolution.x as u32,
+        height: resolution.y as u32,
+        ..default()
+    };
+
+    let mut canvas = Image {
+        texture_descriptor: TextureDescriptor {
+            label: None,
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Bgra8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..default()
+    };
+
+    canvas.resize(size);
+
+    images.add(canvas)
+}
+
+pub(crate) fn spawn_pixel_canvas(commands: &mut Commands, canvas: &PixelCanvas) {
+    commands.spawn((
+        Sprite::from_image(canvas.image.clone()),
+        PixelCanvasSprite,
+        RenderLayers::layer(PIXEL_CANVAS_LAYER),
+    ));
+
+    commands.spawn((
+        Camera2d,
+        Camera {
+            order: 1,
+            clear_color: ClearColorConfig::Custom(Color::BLACK),
+            ..default()
+        },
+        Msaa::Off,
+        RenderLayers::layer(PIXEL_CANVAS_LAYER),
+    ));
+}
+
+pub(crate) fn pixel_camera_target(canvas: &PixelCanvas) -> Camera {
+    Camera {
+        order: -1,
+        target: RenderTarget::Image(canvas.image.clone()),
+        ..default()
+    }
+}
+
+/// Upscales the canvas by the largest integer factor fitting the window, leaving black bars around it.
+pub(crate) fn pixel_canvas_scale_system(
+    mut canvas: ResMut<PixelCanvas>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    mut sprite: Query<&mut Transform, With<PixelCanvasSprite>>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+
+    let ratio = window.size() / canvas.resolution;
+    let scale = ratio.min_element().floor().max(1.);
+
+    if canvas.scale == scale {
+        return;
+    }
+
+    canvas.scale = scale;
+
+    for mut transform in sprite.iter_mut() {
+        transform.scale = Vec3::new(scale, scale, 1.);
+    }
+}
+
+/// Snaps the rendered camera position to whole canvas pixels.
+///
+/// Runs after transform propagation so the camera [`Transform`] keeps its fractional position for following and shaking.
+pub(crate) fn pixel_snap_system(
+    mut camera: Query<(&mut GlobalTransform, &OrthographicProjection), With<PixelCamera>>,
+) {
+    for (mut global_transform, projection) in camera.iter_mut() {
+        let mut transform = global_transform.compute_transform();
+        let pixel_size = projection.scale;
+
+        transform.translation.x = (transform.translation.x / pixel_size).round() * pixel_size;
+        transform.translation.y = (transform.translation.y / pixel_size).round() * pixel_size;
+
+        *global_transform = GlobalTransform::from(transform);
+    }
+}
+
+/// Converts window positions (such as the cursor) to world positions, accounting for the pixel-perfect canvas.
+#[derive(SystemParam)]
+pub struct PixelCameraViewport<'w, 's> {
+    window: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<PixelCamera>>,
+    canvas: Option<Res<'w, PixelCanvas>>,
+}
+
+impl PixelCameraViewport<'_, '_> {
+    pub fn cursor_to_world(&self) -> Option<Vec2> {
+        let cursor = self.window.get_single().ok()?.cursor_position()?;
+
+        self.screen_to_world(cursor)
+    }
+
+    pub fn screen_to_world(&self, screen_position: Vec2) -> Option<Vec2> {
+        let (camera, camera_transform) = self.camera.get_single().ok()?;
+
+        let viewport_position = match &self.canvas {
+            Some(canvas) => {
+                let window = self.window.get_single().ok()?;
+                let position =
+                    (screen_position - window.size() / 2.) / canvas.scale + canvas.resolution / 2.;
+
+                if position.cmplt(Vec2::ZERO).any() || position.cmpgt(canvas.resolution).any() {
+                    return None;
+                }
+
+                position
+            }
+            None => screen_position,
+        };
+
+        camera
+            .viewport_to_world_2d(camera_transform, viewport_position)
+            .ok()
+    }
+}
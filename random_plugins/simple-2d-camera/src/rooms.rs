@@ -0,0 +1,146 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+use crate::framing::TrackedFrame;
+use crate::{clamp_to_bounds, CameraFollowState, PixelCamera, PixelCameraTracked};
+
+/// World-space rectangle the camera is confined to while the tracked entities are inside of it.
+///
+/// Spawned automatically for every LDtk level with the `ldtk` feature, and for Tiled rectangle objects
+/// with the `CameraRoom` class with the `tiled` feature.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct CameraRoom(pub Rect);
+
+#[derive(Clone, Copy, Debug)]
+pub enum RoomTransition {
+    /// Jump straight to the new room.
+    Cut,
+    /// Slide the camera over to the new room.
+    Slide { seconds: f32 },
+}
+
+/// How the camera moves between [`CameraRoom`]s.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct CameraRoomSettings {
+    pub transition: RoomTransition,
+    /// Pause physics while the camera slides to the next room.
+    pub pause_physics: bool,
+}
+
+impl Default for CameraRoomSettings {
+    fn default() -> Self {
+        Self {
+            transition: RoomTransition::Slide { seconds: 0.4 },
+            pause_physics: true,
+        }
+    }
+}
+
+/// The room the camera is currently confined to, if any.
+#[derive(Resource, Default, Debug)]
+pub struct ActiveCameraRoom {
+    pub room: Option<Entity>,
+    transition: Option<ActiveRoomTransition>,
+}
+
+impl ActiveCameraRoom {
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Stops a slide to the next room, leaving the camera where it is.
+    ///
+    /// Physics paused for the slide stays paused, for callers pausing physics themselves such as level transitions.
+    pub fn cancel_transition(&mut self) {
+        self.transition = None;
+    }
+}
+
+#[derive(Debug)]
+struct ActiveRoomTransition {
+    from: Vec2,
+    to: Vec2,
+    timer: Timer,
+}
+
+pub(crate) fn camera_room_system(
+    time: Res<Time>,
+    settings: Res<CameraRoomSettings>,
+    mut active_room: ResMut<ActiveCameraRoom>,
+    mut physics_time: ResMut<Time<Physics>>,
+    rooms: Query<(Entity, &CameraRoom)>,
+    tracked: Query<(&Transform, &PixelCameraTracked), Without<PixelCamera>>,
+    mut camera: Query<
+        (&mut Transform, &OrthographicProjection, &mut CameraFollowState),
+        With<PixelCamera>,
+    >,
+) {
+    let Ok((mut camera, projection, mut state)) = camera.get_single_mut() else {
+        return;
+    };
+
+    if let Some(transition) = active_room.transition.as_mut() {
+        transition.timer.tick(time.delta());
+
+        let t = transition.timer.fraction();
+        let position = transition.from.lerp(transition.to, t * t * (3. - 2. * t));
+
+        camera.translation.x = position.x;
+        camera.translation.y = position.y;
+
+        if transition.timer.finished() {
+            active_room.transition = None;
+            state.focus = None;
+            state.velocity = Vec2::ZERO;
+
+            if settings.pause_physics {
+                physics_time.unpause();
+            }
+        }
+
+        return;
+    }
+
+    let Some(frame) = TrackedFrame::from_targets(
+        tracked
+            .iter()
+            .map(|(transform, tracked)| (transform.translation.truncate(), Vec2::ZERO, tracked)),
+    ) else {
+        return;
+    };
+
+    let track_point = frame.center;
+
+    // Between rooms, keep the camera in the last one.
+    let Some((room_entity, room)) = rooms.iter().find(|(_, room)| room.0.contains(track_point)) else {
+        return;
+    };
+
+    if active_room.room == Some(room_entity) {
+        return;
+    }
+
+    let previous_room = active_room.room.replace(room_entity);
+    let target = clamp_to_bounds(track_point, projection.area.half_size(), room.0);
+    let previous_room_exists = previous_room.is_some_and(|previous| rooms.contains(previous));
+
+    match settings.transition {
+        RoomTransition::Slide { seconds } if previous_room_exists && seconds > 0. => {
+            active_room.transition = Some(ActiveRoomTransition {
+                from: camera.translation.truncate(),
+                to: target,
+                timer: Timer::from_seconds(seconds, TimerMode::Once),
+            });
+
+            if settings.pause_physics {
+                physics_time.pause();
+            }
+        }
+        _ => {
+            camera.translation.x = target.x;
+            camera.translation.y = target.y;
+            state.focus = None;
+            state.velocity = Vec2::ZERO;
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+
+/// Shakes the camera. Every event is its own source, decaying linearly to nothing over `duration` seconds.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CameraShakeEvent {
+    /// Shake strength, from `0` to `1`.
+    pub trauma: f32,
+    pub duration: f32,
+    /// Restricts the shake to this axis. `None` shakes in every direction.
+    pub direction: Option<Dir2>,
+}
+
+impl CameraShakeEvent {
+    pub fn new(trauma: f32, duration: f32) -> Self {
+        Self {
+            trauma,
+            duration,
+            direction: None,
+        }
+    }
+
+    pub fn soft() -> Self {
+        Self::new(0.2, 0.25)
+    }
+
+    pub fn hard() -> Self {
+        Self::new(0.5, 0.5)
+    }
+
+    pub fn with_direction(mut self, direction: Dir2) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+}
+
+/// Global shake tuning.
+#[derive(Resource, Clone, Debug)]
+pub struct CameraShakeSettings {
+    /// Accessibility multiplier applied to every shake. `0` turns screen shake off.
+    pub intensity: f32,
+    /// Offset at full trauma, in world pixels.
+    pub amplitude: f32,
+    /// Oscillations per second.
+    pub frequency: f32,
+    /// Higher powers make low trauma shakes subtler.
+    pub trauma_power: f32,
+}
+
+impl Default for CameraShakeSettings {
+    fn default() -> Self {
+        Self {
+            intensity: 1.,
+            amplitude: 12.,
+            frequency: 15.,
+            trauma_power: 2.,
+        }
+    }
+}
+
+impl CameraShakeSettings {
+    pub fn is_enabled(&self) -> bool {
+        self.intensity > 0.
+    }
+}
+
+struct ShakeSource {
+    trauma: f32,
+    timer: Timer,
+    direction: Option<Dir2>,
+    seed: f32,
+}
+
+#[derive(Component, Default)]
+pub(crate) struct CameraShakeState {
+    sources: Vec<ShakeSource>,
+    next_seed: f32,
+}
+
+pub(crate) fn camera_shake_event_system(
+    settings: Res<CameraShakeSettings>,
+    mut shake_events: EventReader<CameraShakeEvent>,
+    mut cameras: Query<&mut CameraShakeState>,
+) {
+    if !settings.is_enabled() {
+        shake_events.clear();
+        return;
+    }
+
+    for event in shake_events.read() {
+        if event.trauma <= 0. || event.duration <= 0. {
+            continue;
+        }
+
+        for mut state in cameras.iter_mut() {
+            let seed = state.next_seed;
+            state.next_seed += 17.3;
+
+            state.sources.push(ShakeSource {
+                trauma: event.trauma.min(1.),
+                timer: Timer::from_seconds(event.duration, TimerMode::Once),
+                direction: event.direction,
+                seed,
+            });
+        }
+    }
+}
+
+/// Offsets the rendered camera position, leaving its [`Transform`] untouched.
+///
+/// Uses real time so shakes keep going during hit stop.
+pub(crate) fn camera_shake_system(
+    time: Res<Time<Real>>,
+    settings: Res<CameraShakeSettings>,
+    mut cameras: Query<(&mut CameraShakeState, &mut GlobalTransform)>,
+) {
+    let elapsed = time.elapsed_secs();
+
+    for (mut state, mut global_transform) in cameras.iter_mut() {
+        let mut offset = Vec2::ZERO;
+
+        for source in state.sources.iter_mut() {
+            source.timer.tick(time.delta());
+
+            let trauma = source.trauma * source.timer.fraction_remaining();
+            let strength = trauma.powf(settings.trauma_power) * settings.amplitude;
+            let t = elapsed * settings.frequency;
+
+            offset += match source.direction {
+                Some(direction) => direction * noise(source.seed, t) * strength,
+                None => Vec2::new(noise(source.seed, t), noise(source.seed + 7.1, t)) * strength,
+            };
+        }
+
+        state.sources.retain(|source| !source.timer.finished());
+
+        if offset == Vec2::ZERO {
+            continue;
+        }
+
+        let mut transform = global_transform.compute_transform();
+        transform.translation += (offset * settings.intensity).extend(0.);
+        *global_transform = GlobalTransform::from(transform);
+    }
+}
+
+/// Cheap smooth noise in `-1..1`.
+fn noise(seed: f32, t: f32) -> f32 {
+    (t + seed).sin() * 0.6 + (t * 2.3 + seed * 1.7).sin() * 0.3 + (t * 4.1 + seed * 0.3).sin() * 0.1
+}
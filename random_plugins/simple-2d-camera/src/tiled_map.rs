@@ -0,0 +1,281 @@
+use bevy::prelude::*;
+use bevy_ecs_tiled::prelude::*;
+
+use crate::framing::TrackedFrame;
+use crate::parallax::ParallaxOrigin;
+use crate::{
+    CameraBounds, CameraRoom, CameraSequenceTrigger, CameraZoomZone, ParallaxLayer, PixelCamera,
+    PixelCameraTracked,
+};
+
+/// Tiled rectangle objects with this class become [`CameraRoom`]s.
+pub const TILED_CAMERA_ROOM_CLASS: &str = "CameraRoom";
+
+/// Tiled rectangle objects with this class become [`CameraSequenceTrigger`]s.
+///
+/// The `sequence` string property names the sequence to play, and the optional `once` bool property only plays it once.
+pub const TILED_CAMERA_SEQUENCE_TRIGGER_CLASS: &str = "CameraSequenceTrigger";
+
+/// Tiled rectangle objects with this class become [`CameraZoomZone`]s.
+///
+/// The `scale` float property is the zoom to use inside the zone, and the optional `seconds` float property the zoom duration.
+pub const TILED_CAMERA_ZOOM_ZONE_CLASS: &str = "CameraZoomZone";
+
+pub(crate) fn setup_tiled_camera(app: &mut App) {
+    app.add_observer(tiled_map_bounds_observer);
+    app.add_observer(tiled_camera_room_observer);
+    app.add_observer(tiled_camera_sequence_trigger_observer);
+    app.add_observer(tiled_camera_zoom_zone_observer);
+    app.add_observer(tiled_parallax_observer);
+    app.add_systems(
+        Update,
+        tiled_map_bounds_system.before(crate::rooms::camera_room_system),
+    );
+}
+
+/// World-space rectangle covered by a spawned map.
+#[derive(Component, Debug)]
+struct TiledMapBounds(Rect);
+
+fn tiled_map_bounds_observer(
+    trigger: Trigger<TiledMapCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+    map_query: Query<(&Transform, Option<&TiledMapSettings>)>,
+) {
+    let Some(tiled_map) = maps.get(trigger.event().map_handle.id()) else {
+        return;
+    };
+
+    let Ok((map_transform, settings)) = map_query.get(trigger.event().map) else {
+        return;
+    };
+
+    let map_size = map_pixel_size(&tiled_map.map);
+    let local_min = map_origin(&tiled_map.map, settings);
+
+    let min = map_transform.transform_point(local_min.extend(0.)).truncate();
+    let max = map_transform.transform_point((local_min + map_size).extend(0.)).truncate();
+
+    commands
+        .entity(trigger.event().map)
+        .insert(TiledMapBounds(Rect::from_corners(min, max)));
+}
+
+/// Bounds the camera to the map the tracked entities are in, since a world can have several maps loaded at once.
+///
+/// Bounds are kept while the tracked entities are outside of every map, such as when crossing a gap between maps.
+fn tiled_map_bounds_system(
+    maps: Query<&TiledMapBounds>,
+    tracked: Query<(&Transform, &PixelCameraTracked)>,
+    mut bounds: ResMut<CameraBounds>,
+) {
+    if maps.is_empty() {
+        if bounds.0.is_some() {
+            bounds.0 = None;
+        }
+        return;
+    }
+
+    let Some(frame) = TrackedFrame::from_targets(
+        tracked
+            .iter()
+            .map(|(transform, tracked)| (transform.translation.truncate(), Vec2::ZERO, tracked)),
+    ) else {
+        return;
+    };
+
+    let Some(map_bounds) = maps.iter().find(|map| map.0.contains(frame.center)) else {
+        return;
+    };
+
+    if bounds.0 != Some(map_bounds.0) {
+        bounds.0 = Some(map_bounds.0);
+    }
+}
+
+fn tiled_camera_room_observer(
+    trigger: Trigger<TiledObjectCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+    map_query: Query<(&Transform, Option<&TiledMapSettings>)>,
+) {
+    let event = trigger.event();
+    let object = event.object(&maps);
+
+    if object.user_type != TILED_CAMERA_ROOM_CLASS {
+        return;
+    }
+
+    let tiled::ObjectShape::Rect { width, height } = object.shape else {
+        warn!("Camera room {} must be a rectangle", object.name);
+        return;
+    };
+
+    let Ok((map_transform, settings)) = map_query.get(event.map) else {
+        return;
+    };
+
+    // Object positions are their top left corner.
+    let top_left = map_origin(event.map(&maps), settings) + event.world_position(&maps);
+    let bottom_right = top_left + Vec2::new(width, -height);
+
+    let min = map_transform.transform_point(top_left.extend(0.)).truncate();
+    let max = map_transform.transform_point(bottom_right.extend(0.)).truncate();
+
+    commands
+        .entity(event.object)
+        .insert(CameraRoom(Rect::from_corners(min, max)));
+}
+
+fn tiled_camera_sequence_trigger_observer(
+    trigger: Trigger<TiledObjectCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+) {
+    let event = trigger.event();
+    let object = event.object(&maps);
+
+    if object.user_type != TILED_CAMERA_SEQUENCE_TRIGGER_CLASS {
+        return;
+    }
+
+    let tiled::ObjectShape::Rect { width, height } = object.shape else {
+        warn!(
+            "Camera sequence trigger {} must be a rectangle",
+            object.name
+        );
+        return;
+    };
+
+    let Some(tiled::PropertyValue::StringValue(sequence)) = object.properties.get("sequence")
+    else {
+        warn!(
+            "Camera sequence trigger {} has no sequence property",
+            object.name
+        );
+        return;
+    };
+
+    // Object positions are their top left corner.
+    let area = Rect::from_corners(Vec2::ZERO, Vec2::new(width, -height));
+    let camera_trigger = CameraSequenceTrigger::new(sequence.clone(), area);
+
+    let camera_trigger = match object.properties.get("once") {
+        Some(tiled::PropertyValue::BoolValue(true)) => camera_trigger.once(),
+        _ => camera_trigger,
+    };
+
+    commands.entity(event.object).insert(camera_trigger);
+}
+
+fn tiled_camera_zoom_zone_observer(
+    trigger: Trigger<TiledObjectCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+) {
+    let event = trigger.event();
+    let object = event.object(&maps);
+
+    if object.user_type != TILED_CAMERA_ZOOM_ZONE_CLASS {
+        return;
+    }
+
+    let tiled::ObjectShape::Rect { width, height } = object.shape else {
+        warn!("Camera zoom zone {} must be a rectangle", object.name);
+        return;
+    };
+
+    let Some(tiled::PropertyValue::FloatValue(scale)) = object.properties.get("scale") else {
+        warn!("Camera zoom zone {} has no scale property", object.name);
+        return;
+    };
+
+    // Object positions are their top left corner.
+    let area = Rect::from_corners(Vec2::ZERO, Vec2::new(width, -height));
+    let zone = CameraZoomZone::new(*scale, area);
+
+    let zone = match object.properties.get("seconds") {
+        Some(tiled::PropertyValue::FloatValue(seconds)) => zone.with_seconds(*seconds),
+        _ => zone,
+    };
+
+    commands.entity(event.object).insert(zone);
+}
+
+/// Turns image layers with a parallax factor into [`ParallaxLayer`]s.
+///
+/// The `tiled` crate doesn't expose Tiled's repeat flags, so these layers always repeat horizontally only.
+fn tiled_parallax_observer(
+    trigger: Trigger<TiledLayerCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+    map_query: Query<(&Transform, Option<&TiledMapSettings>)>,
+    children: Query<&Children>,
+    map_images: Query<&Transform, With<TiledMapImage>>,
+    camera: Query<&OrthographicProjection, With<PixelCamera>>,
+) {
+    let event = trigger.event();
+    let layer = event.layer(&maps);
+
+    if !matches!(layer.layer_type(), tiled::LayerType::Image(_)) {
+        return;
+    }
+
+    let factor = Vec2::new(layer.parallax_x, layer.parallax_y);
+
+    if factor == Vec2::ONE {
+        return;
+    }
+
+    let Ok((map_transform, settings)) = map_query.get(event.map) else {
+        return;
+    };
+
+    // Tiled lines parallax layers up when the top left corner of the view is on the top left corner of the map.
+    let map = event.map(&maps);
+    let half_view = camera
+        .get_single()
+        .map(|projection| projection.area.half_size())
+        .unwrap_or_default();
+    let top_left = map_origin(map, settings) + Vec2::new(0., map_pixel_size(map).y);
+    let camera_position = map_transform
+        .transform_point((top_left + Vec2::new(half_view.x, -half_view.y)).extend(0.))
+        .truncate();
+
+    for &child in children.get(event.layer).into_iter().flatten() {
+        let Ok(transform) = map_images.get(child) else {
+            continue;
+        };
+
+        commands.entity(child).insert((
+            ParallaxLayer {
+                factor,
+                repeat_x: true,
+                repeat_y: false,
+            },
+            ParallaxOrigin {
+                translation: transform.translation,
+                camera_position,
+            },
+        ));
+    }
+}
+
+fn map_pixel_size(map: &tiled::Map) -> Vec2 {
+    Vec2::new(
+        (map.width * map.tile_width) as f32,
+        (map.height * map.tile_height) as f32,
+    )
+}
+
+/// Bottom left corner of the map, relative to the map entity.
+fn map_origin(map: &tiled::Map, settings: Option<&TiledMapSettings>) -> Vec2 {
+    match settings.map(|settings| settings.layer_positioning) {
+        Some(LayerPositioning::Centered) => {
+            let grid_size = Vec2::new(map.tile_width as f32, map.tile_height as f32);
+            -(map_pixel_size(map) - grid_size) / 2.
+        }
+        _ => Vec2::ZERO,
+    }
+}
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+
+use crate::{PixelCamera, PixelCameraTracked};
+
+/// Runtime camera zoom, as a projection scale (`2` shows twice as much of the world, `0.5` half as much).
+///
+/// In pixel-perfect mode the applied scale is rounded to whole pixel ratios (`…, 1/3, 1/2, 1, 2, 3, …`).
+#[derive(Component, Debug)]
+pub struct CameraZoom {
+    from: f32,
+    target: f32,
+    timer: Timer,
+    /// Integer zoom-out picked by multi-target framing, applied on top of the zoom.
+    pub(crate) framing: u32,
+    pub(crate) pixel_perfect: bool,
+}
+
+impl Default for CameraZoom {
+    fn default() -> Self {
+        Self {
+            from: 1.,
+            target: 1.,
+            timer: Timer::from_seconds(0., TimerMode::Once),
+            framing: 1,
+            pixel_perfect: false,
+        }
+    }
+}
+
+const MIN_ZOOM_SCALE: f32 = 0.1;
+
+impl CameraZoom {
+    pub(crate) fn new(pixel_perfect: bool) -> Self {
+        Self {
+            pixel_perfect,
+            ..default()
+        }
+    }
+
+    /// Tweens from the current zoom to `scale` over `seconds`.
+    pub fn zoom_to(&mut self, scale: f32, seconds: f32) {
+        self.from = self.current();
+        self.target = scale.max(MIN_ZOOM_SCALE);
+        self.timer = Timer::from_seconds(seconds.max(0.), TimerMode::Once);
+    }
+
+    pub fn set(&mut self, scale: f32) {
+        self.zoom_to(scale, 0.);
+    }
+
+    pub fn target(&self) -> f32 {
+        self.target
+    }
+
+    pub fn current(&self) -> f32 {
+        if self.timer.finished() || self.timer.duration().is_zero() {
+            return self.target;
+        }
+
+        let t = self.timer.fraction();
+
+        self.from.lerp(self.target, t * t * (3. - 2. * t))
+    }
+
+    /// Projection scale to apply, including framing and pixel-perfect rounding.
+    pub fn scale(&self) -> f32 {
+        let scale = self.current() * self.framing as f32;
+
+        if !self.pixel_perfect {
+            return scale;
+        }
+
+        if scale >= 1. {
+            scale.round()
+        } else {
+            1. / (1. / scale).round()
+        }
+    }
+}
+
+pub(crate) fn camera_zoom_system(
+    time: Res<Time>,
+    mut camera: Query<(&mut CameraZoom, &mut OrthographicProjection), With<PixelCamera>>,
+) {
+    for (mut zoom, mut projection) in camera.iter_mut() {
+        zoom.timer.tick(time.delta());
+
+        let scale = zoom.scale();
+
+        if projection.scale != scale {
+            projection.scale = scale;
+        }
+    }
+}
+
+/// Zooms the camera to `scale` while a [`PixelCameraTracked`] entity is inside `area`, and back out when it leaves.
+#[derive(Component, Clone, Debug)]
+pub struct CameraZoomZone {
+    pub scale: f32,
+    pub seconds: f32,
+    /// Area relative to the entity position.
+    pub area: Rect,
+    previous_scale: Option<f32>,
+}
+
+impl CameraZoomZone {
+    pub fn new(scale: f32, area: Rect) -> Self {
+        Self {
+            scale,
+            seconds: 0.5,
+            area,
+            previous_scale: None,
+        }
+    }
+
+    pub fn with_seconds(mut self, seconds: f32) -> Self {
+        self.seconds = seconds;
+        self
+    }
+}
+
+pub(crate) fn camera_zoom_zone_system(
+    mut zones: Query<(&mut CameraZoomZone, &GlobalTransform)>,
+    tracked: Query<&GlobalTransform, With<PixelCameraTracked>>,
+    mut camera: Query<&mut CameraZoom, With<PixelCamera>>,
+) {
+    let Ok(mut zoom) = camera.get_single_mut() else {
+        return;
+    };
+
+    for (mut zone, global_transform) in zones.iter_mut() {
+        let offset = global_transform.translation().truncate();
+        let area = Rect::from_corners(zone.area.min + offset, zone.area.max + offset);
+        let occupied = tracked
+            .iter()
+            .any(|transform| area.contains(transform.translation().truncate()));
+
+        match (occupied, zone.previous_scale) {
+            (true, None) => {
+                zone.previous_scale = Some(zoom.target());
+                zoom.zoom_to(zone.scale, zone.seconds);
+            }
+            (false, Some(previous_scale)) => {
+                zone.previous_scale = None;
+                zoom.zoom_to(previous_scale, zone.seconds);
+            }
+            _ => {}
+        }
+    }
+}
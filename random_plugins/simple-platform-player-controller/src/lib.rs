@@ -2,6 +2,7 @@ use avian2d::math::AdjustPrecision;
 use avian2d::prelude::*;
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
+use physics_layers::{player_collision_layers, world_query_filter};
 use simple_2d_camera::PixelCameraTracked;
 
 pub mod player_components;
@@ -60,10 +61,13 @@ impl Plugin for PlayerPlugin {
     RigidBody(|| RigidBody::Dynamic),
     Collider(|| Collider::rectangle(7., 30.)),
     CollisionMargin(|| CollisionMargin::from(COLLISION_MARGIN)),
-    CollisionLayers(|| CollisionLayers::new(0b00001, 0b00101)),
+    CollisionLayers(player_collision_layers),
     ExternalForce(|| ExternalForce::default().with_persistence(false)),
     GravityScale,
-    ShapeCaster(|| ShapeCaster::new(Collider::rectangle(4., 4.), Vec2::ZERO, 0., Dir2::NEG_Y)),
+    ShapeCaster(|| {
+        ShapeCaster::new(Collider::rectangle(4., 4.), Vec2::ZERO, 0., Dir2::NEG_Y)
+            .with_query_filter(world_query_filter())
+    }),
     LockedAxes(|| LockedAxes::ROTATION_LOCKED),
     MovementDampeningFactor(|| MovementDampeningFactor(X_DAMPENING_FACTOR)),
     JumpState,
@@ -343,13 +347,22 @@ fn grounded_system(
             &mut PlayerAnimation,
             &Transform,
             Option<&Attacking>,
+            &mut PixelCameraTracked,
         ),
         With<Player>,
     >,
     spatial_query: SpatialQuery,
 ) {
-    for (entity, hits, mut jump_state_data, velocity, mut animation, player_transform, attacking) in
-        &mut query
+    for (
+        entity,
+        hits,
+        mut jump_state_data,
+        velocity,
+        mut animation,
+        player_transform,
+        attacking,
+        mut camera_tracked,
+    ) in &mut query
     {
         let is_grounded = hits.iter().any(|hit| {
             hit.point2.y < 0.
@@ -369,6 +382,7 @@ fn grounded_system(
 
             if velocity.y >= 0. {
                 commands.entity(entity).insert(Grounded);
+                camera_tracked.grounded = true;
                 jump_state_data.used = 0;
                 jump_state_data.left_ground_at = None;
             }
@@ -381,7 +395,7 @@ fn grounded_system(
                     50.,
                     2,
                     true,
-                    &SpatialQueryFilter::default(),
+                    &world_query_filter(),
                 );
 
                 if up_hits.iter().any(|hit| hit.distance < 18.) {
@@ -390,6 +404,7 @@ fn grounded_system(
             }
 
             commands.entity(entity).remove::<Grounded>();
+            camera_tracked.grounded = false;
             if attacking.is_none() {
                 animation.animation_row = 3;
             }
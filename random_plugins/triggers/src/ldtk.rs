@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::*;
+
+use crate::TriggerKind;
+
+/// LDtk entities named `Trigger` become [`crate::Trigger`]s covering the entity.
+///
+/// The optional `id` string field names the trigger events and defaults to the entity iid, the optional `kind` enum
+/// field (`Enter`, `Exit` or `Stay`) picks when they're sent, and the optional `once` bool field only sends the first
+/// one.
+pub const LDTK_TRIGGER_IDENTIFIER: &str = "Trigger";
+
+pub(crate) fn setup_ldtk_triggers(app: &mut App) {
+    app.register_ldtk_entity::<TriggerBundle>(LDTK_TRIGGER_IDENTIFIER);
+    app.add_systems(
+        Update,
+        ldtk_trigger_scale_system.before(crate::trigger_setup_system),
+    );
+}
+
+#[derive(Bundle, LdtkEntity)]
+struct TriggerBundle {
+    #[with(trigger_from_fields)]
+    trigger: crate::Trigger,
+}
+
+fn trigger_from_fields(entity_instance: &EntityInstance) -> crate::Trigger {
+    let id = entity_instance
+        .get_string_field("id")
+        .cloned()
+        .unwrap_or_else(|_| entity_instance.iid.clone());
+
+    let kind = match entity_instance.get_enum_field("kind") {
+        Ok(name) => TriggerKind::from_name(name).unwrap_or_else(|| {
+            warn!(
+                "Unknown trigger kind {name}, defaulting to {:?}",
+                TriggerKind::default()
+            );
+            TriggerKind::default()
+        }),
+        Err(_) => TriggerKind::default(),
+    };
+
+    let size = Vec2::new(entity_instance.width as f32, entity_instance.height as f32);
+    let trigger = crate::Trigger::new(id, kind, Rect::from_center_size(Vec2::ZERO, size));
+
+    match entity_instance.get_bool_field("once") {
+        Ok(true) => trigger.once(),
+        _ => trigger,
+    }
+}
+
+/// bevy_ecs_ldtk scales resized entities by their size over their definition size, which would scale the trigger area
+/// a second time since it's already the entity size.
+fn ldtk_trigger_scale_system(
+    mut triggers: Query<&mut Transform, (Added<crate::Trigger>, With<EntityInstance>)>,
+) {
+    for mut transform in triggers.iter_mut() {
+        transform.scale = Vec3::ONE;
+    }
+}
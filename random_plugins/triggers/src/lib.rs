@@ -0,0 +1,236 @@
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use physics_layers::{is_player, sensor_collision_layers};
+
+#[cfg(feature = "ldtk")]
+pub mod ldtk;
+#[cfg(feature = "tiled")]
+pub mod tiled_map;
+
+/// Sends [`TriggerEvent`]s when players move through [`Trigger`] volumes.
+///
+/// Triggers can be placed from Tiled with the `tiled` feature, or from LDtk with the `ldtk` feature.
+pub struct TriggerPlugin;
+
+impl Plugin for TriggerPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<TriggerKind>()
+            .add_event::<TriggerEvent>()
+            .add_systems(
+                Update,
+                (
+                    trigger_setup_system,
+                    trigger_collision_system,
+                    trigger_stay_system,
+                )
+                    .chain(),
+            );
+
+        #[cfg(feature = "tiled")]
+        tiled_map::setup_tiled_triggers(app);
+
+        #[cfg(feature = "ldtk")]
+        ldtk::setup_ldtk_triggers(app);
+    }
+}
+
+/// When a [`Trigger`] sends its [`TriggerEvent`]s.
+#[derive(Reflect, Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[reflect(Default)]
+pub enum TriggerKind {
+    /// When a player starts touching the volume.
+    #[default]
+    Enter,
+    /// When a player stops touching the volume.
+    Exit,
+    /// Every frame a player is inside the volume.
+    Stay,
+}
+
+impl TriggerKind {
+    /// Parses the variant name, as written in map properties.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Enter" => Some(TriggerKind::Enter),
+            "Exit" => Some(TriggerKind::Exit),
+            "Stay" => Some(TriggerKind::Stay),
+            _ => None,
+        }
+    }
+}
+
+/// A sensor volume sending [`TriggerEvent`]s named `id` as players move through `area`.
+///
+/// The sensor collider is spawned as a child, so the entity itself can keep its own collider or none.
+#[derive(Component, Clone, Debug)]
+pub struct Trigger {
+    pub id: String,
+    pub kind: TriggerKind,
+    /// Area relative to the entity position.
+    pub area: Rect,
+    /// Only send the first event, instead of every time the condition is met.
+    pub once: bool,
+    occupants: HashSet<Entity>,
+    fired: bool,
+}
+
+impl Trigger {
+    pub fn new(id: impl Into<String>, kind: TriggerKind, area: Rect) -> Self {
+        Self {
+            id: id.into(),
+            kind,
+            area,
+            once: false,
+            occupants: HashSet::default(),
+            fired: false,
+        }
+    }
+
+    pub fn once(mut self) -> Self {
+        self.once = true;
+        self
+    }
+
+    /// Players currently inside the volume.
+    pub fn occupants(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.occupants.iter().copied()
+    }
+
+    /// Whether a `once` trigger already sent its event.
+    pub fn has_fired(&self) -> bool {
+        self.fired
+    }
+
+    fn fire(&mut self) -> bool {
+        if self.once && self.fired {
+            return false;
+        }
+
+        self.fired = true;
+        true
+    }
+}
+
+#[derive(Event, Clone, Debug)]
+pub struct TriggerEvent {
+    pub id: String,
+    pub kind: TriggerKind,
+    pub trigger: Entity,
+    pub player: Entity,
+}
+
+/// Sensor collider of a [`Trigger`], spawned as its child.
+#[derive(Component, Debug)]
+struct TriggerSensor {
+    trigger: Entity,
+}
+
+fn trigger_setup_system(
+    mut commands: Commands,
+    triggers: Query<(Entity, &Trigger), Added<Trigger>>,
+) {
+    for (entity, trigger) in triggers.iter() {
+        let size = trigger.area.size();
+
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                TriggerSensor { trigger: entity },
+                Transform::from_translation(trigger.area.center().extend(0.)),
+                Collider::rectangle(size.x, size.y),
+                Sensor,
+                sensor_collision_layers(),
+            ));
+        });
+    }
+}
+
+/// Tracks players touching each trigger from collision start and end events, like [`CollidingEntities`] but only
+/// for players.
+fn trigger_collision_system(
+    mut started_events: EventReader<CollisionStarted>,
+    mut ended_events: EventReader<CollisionEnded>,
+    mut trigger_events: EventWriter<TriggerEvent>,
+    mut triggers: Query<&mut Trigger>,
+    sensors: Query<&TriggerSensor>,
+    layers: Query<&CollisionLayers>,
+) {
+    let is_player = |entity: Entity| layers.get(entity).is_ok_and(is_player);
+
+    let trigger_player = |a: Entity, b: Entity| {
+        let (sensor, player) = match (sensors.get(a), sensors.get(b)) {
+            (Ok(sensor), _) if is_player(b) => (sensor, b),
+            (_, Ok(sensor)) if is_player(a) => (sensor, a),
+            _ => return None,
+        };
+
+        Some((sensor.trigger, player))
+    };
+
+    let started: Vec<_> = started_events
+        .read()
+        .filter_map(|CollisionStarted(a, b)| trigger_player(*a, *b))
+        .collect();
+    let ended: Vec<_> = ended_events
+        .read()
+        .filter_map(|CollisionEnded(a, b)| trigger_player(*a, *b))
+        .collect();
+
+    for (entity, player) in started {
+        let Ok(mut trigger) = triggers.get_mut(entity) else {
+            continue;
+        };
+
+        if trigger.occupants.insert(player) && trigger.kind == TriggerKind::Enter && trigger.fire()
+        {
+            trigger_events.send(TriggerEvent {
+                id: trigger.id.clone(),
+                kind: TriggerKind::Enter,
+                trigger: entity,
+                player,
+            });
+        }
+    }
+
+    for (entity, player) in ended {
+        let Ok(mut trigger) = triggers.get_mut(entity) else {
+            continue;
+        };
+
+        if trigger.occupants.remove(&player) && trigger.kind == TriggerKind::Exit && trigger.fire()
+        {
+            trigger_events.send(TriggerEvent {
+                id: trigger.id.clone(),
+                kind: TriggerKind::Exit,
+                trigger: entity,
+                player,
+            });
+        }
+    }
+}
+
+fn trigger_stay_system(
+    mut trigger_events: EventWriter<TriggerEvent>,
+    mut triggers: Query<(Entity, &mut Trigger)>,
+) {
+    for (entity, mut trigger) in triggers.iter_mut() {
+        if trigger.kind != TriggerKind::Stay {
+            continue;
+        }
+
+        let occupants: Vec<Entity> = trigger.occupants().collect();
+
+        for player in occupants {
+            if !trigger.fire() {
+                break;
+            }
+
+            trigger_events.send(TriggerEvent {
+                id: trigger.id.clone(),
+                kind: TriggerKind::Stay,
+                trigger: entity,
+                player,
+            });
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use bevy_ecs_tiled::prelude::*;
+
+use crate::TriggerKind;
+
+/// Tiled rectangle objects with this class become [`crate::Trigger`]s.
+///
+/// The optional `id` string property names the trigger events and defaults to the object name, the optional `kind`
+/// string property (`Enter`, `Exit` or `Stay`) picks when they're sent, and the optional `once` bool property only
+/// sends the first one.
+pub const TILED_TRIGGER_CLASS: &str = "Trigger";
+
+pub(crate) fn setup_tiled_triggers(app: &mut App) {
+    app.add_observer(tiled_trigger_observer);
+}
+
+/// Whether the collider comes from a trigger object, whose sensor is spawned by the [`crate::TriggerPlugin`] instead.
+pub fn is_trigger_collider(map: &tiled::Map, collider_source: &TiledColliderSource) -> bool {
+    collider_source
+        .object(map)
+        .is_some_and(|object| object.user_type == TILED_TRIGGER_CLASS)
+}
+
+fn tiled_trigger_observer(
+    trigger: Trigger<TiledObjectCreated>,
+    mut commands: Commands,
+    maps: Res<Assets<TiledMap>>,
+) {
+    let event = trigger.event();
+    let object = event.object(&maps);
+
+    if object.user_type != TILED_TRIGGER_CLASS {
+        return;
+    }
+
+    let tiled::ObjectShape::Rect { width, height } = object.shape else {
+        warn!("Trigger {} must be a rectangle", object.name);
+        return;
+    };
+
+    let id = match object.properties.get("id") {
+        Some(tiled::PropertyValue::StringValue(id)) => id.clone(),
+        _ => object.name.clone(),
+    };
+
+    let kind = match object.properties.get("kind") {
+        Some(tiled::PropertyValue::StringValue(name)) => TriggerKind::from_name(name)
+            .unwrap_or_else(|| {
+                warn!(
+                    "Unknown trigger kind {name}, defaulting to {:?}",
+                    TriggerKind::default()
+                );
+                TriggerKind::default()
+            }),
+        _ => TriggerKind::default(),
+    };
+
+    // Object positions are their top left corner.
+    let area = Rect::from_corners(Vec2::ZERO, Vec2::new(width, -height));
+    let volume = crate::Trigger::new(id, kind, area);
+
+    let volume = match object.properties.get("once") {
+        Some(tiled::PropertyValue::BoolValue(true)) => volume.once(),
+        _ => volume,
+    };
+
+    commands.entity(event.object).insert(volume);
+}
@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+
+use bevy_ecs_ldtk::ldtk::LdtkJson;
+
+use crate::report::Report;
+use crate::{has_extension, is_ldtk_backup, PLAYER_SPAWN};
+
+/// Lints every `.ldtk` project, skipping LDtk's own backups which are reported separately.
+pub fn lint(files: &[PathBuf], report: &mut Report) {
+    for file in files
+        .iter()
+        .filter(|file| has_extension(file, "ldtk") && !is_ldtk_backup(file))
+    {
+        let project = match std::fs::read_to_string(file)
+            .map_err(|error| error.to_string())
+            .and_then(|contents| {
+                serde_json::from_str::<LdtkJson>(&contents).map_err(|error| error.to_string())
+            }) {
+            Ok(project) => project,
+            Err(error) => {
+                report.error(file, "", format!("can't load project: {error}"));
+                continue;
+            }
+        };
+
+        lint_project(file, &project, report);
+    }
+}
+
+fn lint_project(file: &Path, project: &LdtkJson, report: &mut Report) {
+    let mut spawns = 0;
+
+    for level in &project.levels {
+        let mut level_spawns = Vec::new();
+
+        for layer in level.layer_instances.iter().flatten() {
+            for entity in &layer.entity_instances {
+                let location = format!(
+                    "level \"{}\", layer \"{}\", entity {} {}",
+                    level.identifier, layer.identifier, entity.identifier, entity.iid
+                );
+
+                if entity.identifier == PLAYER_SPAWN {
+                    level_spawns.push(location.clone());
+                }
+
+                // Entity positions are their pivot, relative to the level's top left corner.
+                let inside = (0..=level.px_wid).contains(&entity.px.x)
+                    && (0..=level.px_hei).contains(&entity.px.y);
+
+                if !inside {
+                    report.error(
+                        file,
+                        location,
+                        format!(
+                            "at ({}, {}), outside the {}x{} level",
+                            entity.px.x, entity.px.y, level.px_wid, level.px_hei
+                        ),
+                    );
+                }
+            }
+        }
+
+        spawns += level_spawns.len();
+
+        // Levels spawn one at a time, a second spawn in the same level fights the first one.
+        if level_spawns.len() > 1 {
+            for location in &level_spawns {
+                report.error(
+                    file,
+                    location.as_str(),
+                    format!(
+                        "one of {} {PLAYER_SPAWN} entities in the level, only one is used",
+                        level_spawns.len()
+                    ),
+                );
+            }
+        }
+    }
+
+    if spawns == 0 {
+        report.error(file, "", format!("no {PLAYER_SPAWN} entity"));
+    }
+}
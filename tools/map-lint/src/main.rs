@@ -0,0 +1,134 @@
+//! Checks the Tiled and LDtk maps of the workspace for problems the games would only hit at runtime.
+//!
+//! ```text
+//! cargo run -p map-lint -- [--deny-warnings] [PATH...]
+//! ```
+//!
+//! Paths can be map files or directories, searched recursively. Without any, the whole workspace is checked. Exits
+//! with an error when any error is found, or any warning with `--deny-warnings`.
+
+mod ldtk;
+mod report;
+mod tiled_map;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use report::Report;
+
+/// Identifier of the LDtk entity, or last type path segment of the Tiled property class, marking player spawns.
+pub const PLAYER_SPAWN: &str = "PlayerSpawn";
+
+const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+fn main() -> ExitCode {
+    let mut deny_warnings = false;
+    let mut roots = Vec::new();
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--deny-warnings" => deny_warnings = true,
+            "-h" | "--help" => {
+                println!("Usage: map-lint [--deny-warnings] [PATH...]");
+                return ExitCode::SUCCESS;
+            }
+            _ => roots.push(PathBuf::from(arg)),
+        }
+    }
+
+    if roots.is_empty() {
+        roots.push(PathBuf::from(WORKSPACE_ROOT));
+    }
+
+    let mut files = Vec::new();
+
+    for root in &roots {
+        collect_files(root, &mut files);
+    }
+
+    files.sort();
+    files.dedup();
+
+    let mut report = Report::default();
+
+    tiled_map::lint(&files, &mut report);
+    ldtk::lint(&files, &mut report);
+    lint_backups(&files, &mut report);
+
+    report.print();
+
+    if report.errors() > 0 || (deny_warnings && report.warnings() > 0) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Every file under `path`, skipping build output, hidden directories and symlinks (kata asset folders link to the
+/// shared one).
+fn collect_files(path: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        eprintln!("map-lint: can't read {}", path.display());
+        return;
+    };
+
+    if metadata.is_file() {
+        files.push(path.to_path_buf());
+        return;
+    }
+
+    if !metadata.is_dir() {
+        return;
+    }
+
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+
+        if name.starts_with('.') || name == "target" {
+            continue;
+        }
+
+        if entry
+            .file_type()
+            .is_ok_and(|file_type| file_type.is_symlink())
+        {
+            continue;
+        }
+
+        collect_files(&path, files);
+    }
+}
+
+/// LDtk keeps backups next to the project, and editors leave `.bak`/`~` copies. The games never load them, but they
+/// end up in the asset folder shipped with the game.
+fn lint_backups(files: &[PathBuf], report: &mut Report) {
+    for file in files {
+        let name = file
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
+
+        let is_editor_backup =
+            name.ends_with('~') || name.ends_with(".bak") || name.ends_with(".orig");
+
+        if is_ldtk_backup(file) || is_editor_backup {
+            report.warning(file, "", "backup file, not loaded by any game");
+        }
+    }
+}
+
+/// LDtk saves backups in a `backups` folder next to the project.
+fn is_ldtk_backup(file: &Path) -> bool {
+    file.components()
+        .any(|component| component.as_os_str() == "backups")
+}
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension().is_some_and(|found| found == extension)
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    pub severity: Severity,
+    pub file: PathBuf,
+    /// Where in the file, such as `layer "spawn", object 1`. Empty for the whole file.
+    pub location: String,
+    pub message: String,
+}
+
+#[derive(Default, Debug)]
+pub struct Report {
+    problems: Vec<Problem>,
+}
+
+impl Report {
+    pub fn error(&mut self, file: &Path, location: impl Into<String>, message: impl Into<String>) {
+        self.push(Severity::Error, file, location.into(), message.into());
+    }
+
+    pub fn warning(
+        &mut self,
+        file: &Path,
+        location: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        self.push(Severity::Warning, file, location.into(), message.into());
+    }
+
+    fn push(&mut self, severity: Severity, file: &Path, location: String, message: String) {
+        self.problems.push(Problem {
+            severity,
+            file: file.to_path_buf(),
+            location,
+            message,
+        });
+    }
+
+    pub fn errors(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.problems
+            .iter()
+            .filter(|problem| problem.severity == severity)
+            .count()
+    }
+
+    pub fn print(&self) {
+        for problem in &self.problems {
+            let file = display_path(&problem.file);
+
+            if problem.location.is_empty() {
+                println!("{}: {file}: {}", problem.severity, problem.message);
+            } else {
+                println!(
+                    "{}: {file}: {}: {}",
+                    problem.severity, problem.location, problem.message
+                );
+            }
+        }
+
+        println!(
+            "map-lint: {} error(s), {} warning(s)",
+            self.errors(),
+            self.warnings()
+        );
+    }
+}
+
+/// Paths relative to the current directory when possible, they're easier to read and click.
+fn display_path(path: &Path) -> String {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+    std::env::current_dir()
+        .ok()
+        .and_then(|current_dir| path.strip_prefix(current_dir).ok().map(Path::to_path_buf))
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
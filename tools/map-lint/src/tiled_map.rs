@@ -0,0 +1,379 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use tiled::{
+    Layer, LayerType, Loader, Map, ObjectData, ObjectShape, Orientation, Properties, PropertyValue,
+    Tileset,
+};
+
+use crate::report::Report;
+use crate::{has_extension, PLAYER_SPAWN};
+
+/// Collision shapes thinner than this, in pixels, are most likely drawing mistakes.
+pub const MIN_COLLIDER_SIZE: f32 = 1.;
+
+/// A player spawn object, for reporting duplicates.
+struct Spawn {
+    file: PathBuf,
+    location: String,
+}
+
+/// Lints every `.tmx` map, and every map of the `.world` files.
+///
+/// Maps of a world are loaded together, so the world as a whole needs exactly one player spawn.
+pub fn lint(files: &[PathBuf], report: &mut Report) {
+    let worlds: Vec<(PathBuf, Vec<PathBuf>)> = files
+        .iter()
+        .filter(|file| has_extension(file, "world"))
+        .filter_map(|file| Some((file.clone(), world_maps(file, report)?)))
+        .collect();
+
+    let maps: BTreeSet<PathBuf> = files
+        .iter()
+        .filter(|file| has_extension(file, "tmx"))
+        .filter_map(|file| file.canonicalize().ok())
+        .chain(worlds.iter().flat_map(|(_, maps)| maps.iter().cloned()))
+        .collect();
+
+    let mut loader = Loader::new();
+    let mut linter = TiledLinter::default();
+    let mut spawns: BTreeMap<PathBuf, Vec<Spawn>> = BTreeMap::new();
+
+    for file in &maps {
+        match loader.load_tmx_map(file) {
+            Ok(map) => {
+                let map_spawns = linter.lint_map(file, &map, report);
+                spawns.insert(file.clone(), map_spawns);
+            }
+            Err(error) => report.error(file, "", format!("can't load map: {error}")),
+        }
+    }
+
+    let mut world_maps = HashSet::new();
+
+    for (world, maps) in &worlds {
+        let world_spawns: Vec<&Spawn> = maps
+            .iter()
+            .filter_map(|map| spawns.get(map))
+            .flatten()
+            .collect();
+
+        check_spawns(world, &world_spawns, report);
+        world_maps.extend(maps);
+    }
+
+    for (map, map_spawns) in &spawns {
+        // Editor sources are checked through the map they export to.
+        let exported = export_target(map).is_some_and(|target| spawns.contains_key(&target));
+
+        if !world_maps.contains(map) && !exported {
+            check_spawns(map, &map_spawns.iter().collect::<Vec<_>>(), report);
+        }
+    }
+}
+
+/// Kata 2 places the player with `get_single`, any other number of spawns leaves it at the default position.
+fn check_spawns(file: &Path, spawns: &[&Spawn], report: &mut Report) {
+    match spawns {
+        [] => report.error(file, "", format!("no {PLAYER_SPAWN} object")),
+        [_] => {}
+        _ => {
+            for spawn in spawns {
+                report.error(
+                    &spawn.file,
+                    spawn.location.as_str(),
+                    format!(
+                        "one of {} {PLAYER_SPAWN} objects, only one is used",
+                        spawns.len()
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Canonical path of the map exported from `file`, as set in its Tiled editor settings.
+fn export_target(file: &Path) -> Option<PathBuf> {
+    const EXPORT_TARGET: &str = "<export target=\"";
+
+    let contents = std::fs::read_to_string(file).ok()?;
+    let start = contents.find(EXPORT_TARGET)? + EXPORT_TARGET.len();
+    let end = start + contents[start..].find('"')?;
+
+    file.parent()?
+        .join(&contents[start..end])
+        .canonicalize()
+        .ok()
+}
+
+/// Canonical paths of the maps listed in a Tiled `.world` file.
+fn world_maps(file: &Path, report: &mut Report) -> Option<Vec<PathBuf>> {
+    let world: Value = match std::fs::read_to_string(file)
+        .map_err(|error| error.to_string())
+        .and_then(|contents| serde_json::from_str(&contents).map_err(|error| error.to_string()))
+    {
+        Ok(world) => world,
+        Err(error) => {
+            report.error(file, "", format!("can't load world: {error}"));
+            return None;
+        }
+    };
+
+    let directory = file.parent().unwrap_or(Path::new("."));
+    let mut maps = Vec::new();
+
+    for (index, map) in world["maps"].as_array().into_iter().flatten().enumerate() {
+        let Some(file_name) = map["fileName"].as_str() else {
+            report.error(file, format!("map {index}"), "no fileName");
+            continue;
+        };
+
+        match directory.join(file_name).canonicalize() {
+            Ok(path) => maps.push(path),
+            Err(_) => report.error(
+                file,
+                format!("map {index}"),
+                format!("{file_name} doesn't exist"),
+            ),
+        }
+    }
+
+    Some(maps)
+}
+
+#[derive(Default)]
+struct TiledLinter {
+    /// Property classes of the Tiled project of each directory, `None` when there's no project.
+    project_classes: HashMap<PathBuf, Option<HashSet<String>>>,
+    /// External tilesets already linted through another map.
+    linted_tilesets: HashSet<PathBuf>,
+}
+
+impl TiledLinter {
+    fn lint_map(&mut self, file: &Path, map: &Map, report: &mut Report) -> Vec<Spawn> {
+        let classes = self.project_classes(file);
+        let mut context = MapContext {
+            file,
+            map,
+            classes: classes.as_ref(),
+            report,
+            spawns: Vec::new(),
+        };
+
+        context.check_properties(&map.properties, "map");
+
+        for layer in map.layers() {
+            context.lint_layer(&layer, "");
+        }
+
+        for tileset in map.tilesets() {
+            // Embedded tilesets have the map as their source.
+            let is_external = tileset.source != file;
+
+            if is_external && !self.linted_tilesets.insert(tileset.source.clone()) {
+                continue;
+            }
+
+            context.lint_tileset(tileset, is_external);
+        }
+
+        context.spawns
+    }
+
+    /// Classes of the `.tiled-project` next to the map or in a parent directory.
+    fn project_classes(&mut self, file: &Path) -> Option<HashSet<String>> {
+        let directory = file.parent()?.to_path_buf();
+
+        self.project_classes
+            .entry(directory.clone())
+            .or_insert_with(|| {
+                directory.ancestors().find_map(|ancestor| {
+                    let entries = std::fs::read_dir(ancestor).ok()?;
+
+                    entries
+                        .flatten()
+                        .map(|entry| entry.path())
+                        .find(|path| has_extension(path, "tiled-project"))
+                        .and_then(|project| read_project_classes(&project))
+                })
+            })
+            .clone()
+    }
+}
+
+fn read_project_classes(project: &Path) -> Option<HashSet<String>> {
+    let project: Value = serde_json::from_str(&std::fs::read_to_string(project).ok()?).ok()?;
+
+    Some(
+        project["propertyTypes"]
+            .as_array()?
+            .iter()
+            .filter_map(|property_type| property_type["name"].as_str().map(str::to_string))
+            .collect(),
+    )
+}
+
+struct MapContext<'a> {
+    file: &'a Path,
+    map: &'a Map,
+    classes: Option<&'a HashSet<String>>,
+    report: &'a mut Report,
+    spawns: Vec<Spawn>,
+}
+
+impl MapContext<'_> {
+    fn lint_layer(&mut self, layer: &Layer, parent: &str) {
+        let layer_path = format!("{parent}{}", layer.name);
+        let location = format!("layer \"{layer_path}\"");
+
+        self.check_properties(&layer.properties, &location);
+
+        match layer.layer_type() {
+            LayerType::Objects(object_layer) => {
+                for object in object_layer.objects() {
+                    let mut location = format!("{location}, object {}", object.id());
+
+                    if !object.name.is_empty() {
+                        location.push_str(&format!(" \"{}\"", object.name));
+                    }
+
+                    self.lint_object(&object, location);
+                }
+            }
+            LayerType::Group(group) => {
+                for child in group.layers() {
+                    self.lint_layer(&child, &format!("{layer_path}/"));
+                }
+            }
+            LayerType::Tiles(_) | LayerType::Image(_) => {}
+        }
+    }
+
+    fn lint_object(&mut self, object: &ObjectData, location: String) {
+        self.check_properties(&object.properties, &location);
+
+        if is_player_spawn(object) {
+            self.spawns.push(Spawn {
+                file: self.file.to_path_buf(),
+                location: location.clone(),
+            });
+        }
+
+        // Every map object gets a collider.
+        self.check_collider_size(object, &location);
+
+        if self.map.orientation == Orientation::Orthogonal && !self.map.infinite() {
+            let width = (self.map.width * self.map.tile_width) as f32;
+            let height = (self.map.height * self.map.tile_height) as f32;
+
+            if object.x < 0. || object.y < 0. || object.x > width || object.y > height {
+                self.report.error(
+                    self.file,
+                    location,
+                    format!(
+                        "at ({}, {}), outside the {width}x{height} map",
+                        object.x, object.y
+                    ),
+                );
+            }
+        }
+    }
+
+    fn lint_tileset(&mut self, tileset: &Tileset, is_external: bool) {
+        let file = if is_external {
+            tileset.source.as_path()
+        } else {
+            self.file
+        };
+
+        for (id, tile) in tileset.tiles() {
+            let location = format!("tileset \"{}\", tile {id}", tileset.name);
+
+            self.check_properties_in(file, &tile.properties, &location);
+
+            for object in tile
+                .collision
+                .iter()
+                .flat_map(|collision| collision.object_data())
+            {
+                let location = format!("{location}, collision object {}", object.id());
+
+                if object.rotation != 0. {
+                    self.report.warning(
+                        file,
+                        location.as_str(),
+                        format!(
+                            "rotated by {}°, it can't be merged with its neighbours",
+                            object.rotation
+                        ),
+                    );
+                }
+
+                self.check_collider_size_in(file, object, &location);
+            }
+        }
+    }
+
+    fn check_collider_size(&mut self, object: &ObjectData, location: &str) {
+        self.check_collider_size_in(self.file, object, location);
+    }
+
+    fn check_collider_size_in(&mut self, file: &Path, object: &ObjectData, location: &str) {
+        let (ObjectShape::Rect { width, height } | ObjectShape::Ellipse { width, height }) =
+            object.shape
+        else {
+            return;
+        };
+
+        if width < MIN_COLLIDER_SIZE || height < MIN_COLLIDER_SIZE {
+            self.report.warning(
+                file,
+                location,
+                format!("{width}x{height} sliver, thinner than {MIN_COLLIDER_SIZE} pixel"),
+            );
+        }
+    }
+
+    fn check_properties(&mut self, properties: &Properties, location: &str) {
+        self.check_properties_in(self.file, properties, location);
+    }
+
+    fn check_properties_in(&mut self, file: &Path, properties: &Properties, location: &str) {
+        let Some(classes) = self.classes else {
+            return;
+        };
+
+        // Sorted for a stable report.
+        let properties: BTreeMap<&String, &PropertyValue> = properties.iter().collect();
+
+        for (name, value) in properties {
+            let PropertyValue::ClassValue {
+                property_type,
+                properties,
+            } = value
+            else {
+                continue;
+            };
+
+            if !classes.contains(property_type) {
+                self.report.error(
+                    file,
+                    location,
+                    format!("property {name} has unknown class {property_type}"),
+                );
+            }
+
+            self.check_properties_in(file, properties, &format!("{location}, property {name}"));
+        }
+    }
+}
+
+fn is_player_spawn(object: &ObjectData) -> bool {
+    let is_spawn_type = |type_path: &str| type_path.rsplit("::").next() == Some(PLAYER_SPAWN);
+
+    is_spawn_type(&object.user_type)
+        || object.properties.values().any(|value| {
+            matches!(value, PropertyValue::ClassValue { property_type, .. } if is_spawn_type(property_type))
+        })
+}